@@ -0,0 +1,80 @@
+//! Generates standard field catalog of `fix_message_fields` from
+//! `spec/fields.xml`
+extern crate roxmltree;
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const SPEC: &str = "spec/fields.xml";
+
+fn main() {
+  println!("cargo:rerun-if-changed={}", SPEC);
+
+  let xml = fs::read_to_string(SPEC).unwrap_or_else(|err| panic!("Failed to read {}: {}", SPEC, err));
+  let document = roxmltree::Document::parse(&xml).unwrap_or_else(|err| panic!("Invalid {}: {}", SPEC, err));
+
+  let mut fields: Vec<(u32, &str, &str)> = document.root_element().children()
+    .filter(|node| node.has_tag_name("fields"))
+    .flat_map(|section| section.children())
+    .filter(|node| node.has_tag_name("field"))
+    .map(|node| {
+      let attribute = |name| node.attribute(name).unwrap_or_else(|| panic!("Field has no `{}` attribute in {}", name, SPEC));
+      let tag = attribute("number").parse().unwrap_or_else(|_| panic!("Invalid field number in {}", SPEC));
+      (tag, attribute("name"), attribute("type"))
+    })
+    .collect();
+  fields.sort();
+
+  let mut by_name: Vec<(&str, u32)> = fields.iter().map(|&(tag, name, _)| (name, tag)).collect();
+  by_name.sort();
+  if let Some(pair) = fields.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+    panic!("Field {} is defined more than once in {}", pair[0].0, SPEC);
+  }
+  if let Some(pair) = by_name.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+    panic!("Field {} is defined more than once in {}", pair[0].0, SPEC);
+  }
+
+  let mut code = String::new();
+  for &(tag, name, _) in &fields {
+    writeln!(code, "/// `{} - {}`", tag, name).unwrap();
+    writeln!(code, "pub const {}: u32 = {};", const_name(name), tag).unwrap();
+    writeln!(code).unwrap();
+  }
+
+  writeln!(code, "/// Standard fields ordered by tag").unwrap();
+  writeln!(code, "pub const FIELDS: &[FieldInfo] = &[").unwrap();
+  for &(tag, name, type_name) in &fields {
+    writeln!(code, "  FieldInfo {{ tag: {}, name: {:?}, type_name: {:?} }},", tag, name, type_name).unwrap();
+  }
+  writeln!(code, "];").unwrap();
+  writeln!(code).unwrap();
+
+  writeln!(code, "// Tags of standard fields ordered by name").unwrap();
+  writeln!(code, "const TAGS_BY_NAME: &[(&str, u32)] = &[").unwrap();
+  for &(name, tag) in &by_name {
+    writeln!(code, "  ({:?}, {}),", name, tag).unwrap();
+  }
+  writeln!(code, "];").unwrap();
+
+  let path = Path::new(&env::var("OUT_DIR").unwrap()).join("fix_message_fields.rs");
+  fs::write(path, code).unwrap();
+}
+
+// Field name in screaming snake case, e.g. `ClOrdID` to `CL_ORD_ID`
+fn const_name(name: &str) -> String {
+  let chars: Vec<char> = name.replace("IDs", "Ids").chars().collect();
+  let mut ident = String::new();
+  for (index, &c) in chars.iter().enumerate() {
+    if c.is_uppercase() && index > 0 {
+      let previous = chars[index - 1];
+      let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+      if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lowercase) {
+        ident.push('_');
+      }
+    }
+    ident.extend(c.to_uppercase());
+  }
+  ident
+}
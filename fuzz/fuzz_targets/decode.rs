@@ -0,0 +1,10 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  let mut decoder = fix_message::FIXFrameDecoder::new();
+  for chunk in data.chunks(7) {
+    decoder.feed(chunk);
+    while decoder.decode().is_some() {}
+  }
+});
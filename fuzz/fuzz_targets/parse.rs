@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+  if let Ok(message) = fix_message::parse_bytes(data) {
+    let _ = fix_message::generate_bytes(&message);
+  }
+  if let Err(err) = fix_message::parse_borrowed(data) {
+    let _ = err.to_string();
+  }
+});
@@ -0,0 +1,37 @@
+//! Generates typed message structures from QuickFIX XML data dictionary and
+//! writes them to standard output, see `CodeGenerator`
+//!
+//! ```text
+//! fix_codegen <dictionary.xml> [MsgType ...] > messages.rs
+//! ```
+extern crate fix_message;
+
+use std::env;
+use std::process;
+
+use fix_message::{CodeGenerator, DataDictionary};
+
+fn main() {
+  let args: Vec<String> = env::args().skip(1).collect();
+  if args.is_empty() {
+    eprintln!("Usage: fix_codegen <dictionary.xml> [MsgType ...]");
+    process::exit(2);
+  }
+
+  let dictionary = DataDictionary::from_file(&args[0]).unwrap_or_else(|err| {
+    eprintln!("{}", err);
+    process::exit(1);
+  });
+
+  let msg_types: Vec<&str> = args[1..].iter().map(String::as_str).collect();
+  if let Some(msg_type) = msg_types.iter().find(|msg_type| dictionary.message(msg_type).is_none()) {
+    eprintln!("Message type is not defined in data dictionary: `{}`", msg_type);
+    process::exit(1);
+  }
+
+  let mut generator = CodeGenerator::new(&dictionary);
+  if !msg_types.is_empty() {
+    generator = generator.with_messages(&msg_types);
+  }
+  print!("{}", generator.generate());
+}
@@ -0,0 +1,201 @@
+use super::{
+  FIXMessageResult, FIXMessage, FIXMessageField, FixVersion, MsgType, ToFixValue, UtcTimestamp, generate_bytes
+};
+use super::fix_message_fields::{MSG_TYPE, SENDING_TIME, header_field_position};
+
+/// This structure builds `FIXMessage` field by field
+///
+/// Header fields are placed after `35 - MsgType` in the order of the standard
+/// header regardless of the order they are added in, fields which are not
+/// standard header fields follow standard ones. Body fields and groups keep
+/// the order they are added in. `8 - BeginString`, `9 - BodyLength` and
+/// `10 - CheckSum` are added by the generator, see `generate_bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let message = FIXMessageBuilder::new(FixVersion::Fix44, MsgType::NewOrderSingle)
+///   .field(11, "ORD1")
+///   .header(34, &2u64)
+///   .header(56, "INVMGR")
+///   .header(49, "BRKR")
+///   .header(52, "19980604-07:58:28")
+///   .group(453, vec![
+///     FIXGroupEntryBuilder::new().field(448, "PARTY1").field(452, &1u32),
+///     FIXGroupEntryBuilder::new().field(448, "PARTY2").field(452, &3u32),
+///   ])
+///   .field(54, &'1')
+///   .build();
+///
+/// let tags = message.data.iter().map(|field| field.tag).collect::<Vec<u32>>();
+/// assert_eq!(message.version, "FIX.4.4");
+/// assert_eq!(tags, vec![35, 49, 56, 34, 52, 11, 453, 448, 452, 448, 452, 54]);
+///
+/// let generated = FIXMessageBuilder::new(FixVersion::Fix42, MsgType::Heartbeat)
+///   .header(49, "BRKR")
+///   .header(56, "INVMGR")
+///   .header(34, &235u64)
+///   .header(52, "19980604-07:58:28")
+///   .generate_bytes()
+///   .unwrap();
+///
+/// assert_eq!(generated, b"8=FIX.4.2\x019=51\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+///   52=19980604-07:58:28\x0110=146\x01".to_vec());
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct FIXMessageBuilder {
+  version: FixVersion,
+  msg_type: MsgType,
+  header: Vec<FIXMessageField>,
+  body: Vec<FIXMessageField>,
+  sending_time: bool,
+}
+
+impl FIXMessageBuilder {
+  pub fn new(version: FixVersion, msg_type: MsgType) -> FIXMessageBuilder {
+    FIXMessageBuilder { version, msg_type, header: vec![], body: vec![], sending_time: false }
+  }
+
+  /// Adds header field, e.g. `49 - SenderCompID`
+  pub fn header<T: ToFixValue + ?Sized>(mut self, tag: u32, value: &T) -> FIXMessageBuilder {
+    self.header.push(FIXMessageField::from_value(tag, value));
+    self
+  }
+
+  /// Adds body field
+  pub fn field<T: ToFixValue + ?Sized>(mut self, tag: u32, value: &T) -> FIXMessageBuilder {
+    self.body.push(FIXMessageField::from_value(tag, value));
+    self
+  }
+
+  /// Adds repeating group to the body, `count_tag` field is set to the number
+  /// of `entries`
+  pub fn group(mut self, count_tag: u32, entries: Vec<FIXGroupEntryBuilder>) -> FIXMessageBuilder {
+    push_group(&mut self.body, count_tag, entries);
+    self
+  }
+
+  /// Sets `52 - SendingTime` to the time of `build` unless it is added with
+  /// `header`
+  pub fn with_sending_time(mut self) -> FIXMessageBuilder {
+    self.sending_time = true;
+    self
+  }
+
+  /// Returns built message, the builder can be reused to build more messages
+  pub fn build(&self) -> FIXMessage {
+    let mut header = self.header.clone();
+    if self.sending_time && header.iter().all(|field| field.tag != SENDING_TIME) {
+      header.push(FIXMessageField::from_value(SENDING_TIME, &UtcTimestamp::now()));
+    }
+    header.sort_by_key(|field| header_field_position(field.tag).unwrap_or(usize::MAX));
+
+    let mut data = Vec::with_capacity(header.len() + self.body.len() + 1);
+    data.push(FIXMessageField::from_value(MSG_TYPE, &self.msg_type));
+    data.extend(header);
+    data.extend(self.body.iter().cloned());
+    FIXMessage { version: self.version.to_string(), data }
+  }
+
+  /// Builds message and generates it with `generate_bytes`
+  ///
+  /// # Errors
+  /// Returns the errors of `generate_bytes`, e.g. `NotAllRequiredFieldsFound`
+  /// when standard header fields are not added
+  pub fn generate_bytes(&self) -> FIXMessageResult<Vec<u8>> {
+    generate_bytes(&self.build())
+  }
+}
+
+/// This structure builds entry of repeating group, see
+/// `FIXMessageBuilder::group`
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// // NoPartyIDs(453) entry with nested NoPartySubIDs(802)
+/// let party = FIXGroupEntryBuilder::new()
+///   .field(448, "PARTY1")
+///   .group(802, vec![FIXGroupEntryBuilder::new().field(523, "SUB1").field(803, &2u32)]);
+///
+/// let message = FIXMessageBuilder::new(FixVersion::Fix44, MsgType::NewOrderSingle)
+///   .group(453, vec![party])
+///   .build();
+///
+/// let tags = message.data.iter().map(|field| field.tag).collect::<Vec<u32>>();
+/// assert_eq!(tags, vec![35, 453, 448, 802, 523, 803]);
+/// assert_eq!(message.get_as::<u32>(802), Ok(1));
+/// ```
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct FIXGroupEntryBuilder {
+  fields: Vec<FIXMessageField>,
+}
+
+impl FIXGroupEntryBuilder {
+  pub fn new() -> FIXGroupEntryBuilder {
+    FIXGroupEntryBuilder { fields: vec![] }
+  }
+
+  /// Adds entry field, the first field of entry must be delimiter field of the
+  /// group
+  pub fn field<T: ToFixValue + ?Sized>(mut self, tag: u32, value: &T) -> FIXGroupEntryBuilder {
+    self.fields.push(FIXMessageField::from_value(tag, value));
+    self
+  }
+
+  /// Adds nested repeating group, `count_tag` field is set to the number of
+  /// `entries`
+  pub fn group(mut self, count_tag: u32, entries: Vec<FIXGroupEntryBuilder>) -> FIXGroupEntryBuilder {
+    push_group(&mut self.fields, count_tag, entries);
+    self
+  }
+}
+
+fn push_group(fields: &mut Vec<FIXMessageField>, count_tag: u32, entries: Vec<FIXGroupEntryBuilder>) {
+  fields.push(FIXMessageField::from_value(count_tag, &entries.len()));
+  for entry in entries {
+    fields.extend(entry.fields);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::{TimestampPrecision, parse_bytes};
+
+  #[test]
+  fn it_should_order_header_fields() {
+    let message = FIXMessageBuilder::new(FixVersion::Fix44, MsgType::Heartbeat)
+      .header(5000, "CUSTOM")
+      .header(628, "HOP1")
+      .header(52, "19980604-07:58:28")
+      .header(630, "REF1")
+      .header(627, &2u32)
+      .header(628, "HOP2")
+      .header(43, &true)
+      .build();
+
+    let tags = message.data.iter().map(|field| field.tag).collect::<Vec<u32>>();
+    assert_eq!(tags, vec![35, 43, 52, 627, 628, 630, 628, 5000]);
+  }
+
+  #[test]
+  fn it_should_fill_sending_time() {
+    let builder = FIXMessageBuilder::new(FixVersion::Fix42, MsgType::Heartbeat)
+      .header(49, "BRKR")
+      .header(56, "INVMGR")
+      .header(34, &1u64)
+      .with_sending_time();
+
+    let message = parse_bytes(&builder.generate_bytes().unwrap()).unwrap();
+    assert_eq!(message.get_as::<UtcTimestamp>(SENDING_TIME).unwrap().precision(), TimestampPrecision::Milliseconds);
+
+    let message = builder.header(52, "19980604-07:58:28").build();
+    assert_eq!(message.get_str(SENDING_TIME), Some("19980604-07:58:28"));
+    assert_eq!(message.data.iter().filter(|field| field.tag == SENDING_TIME).count(), 1);
+  }
+}
@@ -0,0 +1,67 @@
+use fix_checksum::FIXChecksumValidatorError;
+use fix_checksum::FIXChecksumValidatorError::{InvalidEmptyMessage, ChecksumFieldNotFound,
+  ChecksumFieldInvalidFormat};
+
+use super::FIX_MESSAGE_DELIMITER_BYTE;
+
+const FIX_CHECKSUM_FIELD: &[u8] = b"\x0110=";
+
+/// Calculates checksum of FIX message bytes, the same way `fix_checksum` does
+/// for strings
+pub(crate) fn checksum(message: &[u8]) -> u32 {
+  message.iter().fold(0, |cs: u32, &byte| (cs + u32::from(byte)) % 256)
+}
+
+/// Looks up the last `10 - CheckSum` field and returns offset of its first
+/// byte
+pub(crate) fn find_checksum_field(inbound_message: &[u8]) -> Option<usize> {
+  inbound_message.windows(FIX_CHECKSUM_FIELD.len())
+    .rposition(|window| window == FIX_CHECKSUM_FIELD)
+    .map(|tail_start| tail_start + 1)
+}
+
+/// Validates checksum of FIX message bytes. Mirrors `fix_checksum::validate`,
+/// but looks up the last `10 - CheckSum` field, so that checksum pattern met
+/// inside a field value is not taken for the message trailer.
+pub(crate) fn validate(inbound_message: &[u8]) -> Result<bool, FIXChecksumValidatorError> {
+  if inbound_message.is_empty() { return Err(InvalidEmptyMessage) }
+
+  let split_index = find_checksum_field(inbound_message).ok_or(ChecksumFieldNotFound)?;
+  let checksum_value = &inbound_message[split_index + FIX_CHECKSUM_FIELD.len() - 1..];
+  let checksum_value = checksum_value.strip_suffix(&[FIX_MESSAGE_DELIMITER_BYTE]).unwrap_or(checksum_value);
+
+  let checksum_as_is: u32 = String::from_utf8_lossy(checksum_value)
+    .parse().map_err(ChecksumFieldInvalidFormat)?;
+
+  Ok(checksum_value.len() == 3 && checksum_as_is == checksum(&inbound_message[..split_index]))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_calculate_fix_message_checksum() {
+    assert_eq!(checksum(b"8=FIX.4.2\x019=5\x0135=0\x01"), 161);
+    assert_eq!(checksum(b"\xff\xff"), 254);
+  }
+
+  #[test]
+  fn it_should_validate_fix_message_checksum() {
+    assert_eq!(validate(b"").unwrap_err(), InvalidEmptyMessage);
+    assert_eq!(validate(b"8=FIX.4.2\x019=5\x0135=0\x01").unwrap_err(), ChecksumFieldNotFound);
+    assert_eq!(validate(b"8=FIX.4.2\x019=5\x0135=0\x0110=1X3").unwrap_err(),
+      ChecksumFieldInvalidFormat("1X3".parse::<u32>().unwrap_err()));
+    assert!(validate(b"8=FIX.4.2\x019=5\x0135=0\x0110=161").unwrap());
+    assert!(validate(b"8=FIX.4.2\x019=5\x0135=0\x0110=161\x01").unwrap());
+    assert!(!validate(b"8=FIX.4.2\x019=5\x0135=0\x0110=16").unwrap());
+    assert!(!validate(b"8=FIX.4.2\x019=5\x0135=0\x0110=162").unwrap());
+  }
+
+  #[test]
+  fn it_should_validate_the_last_checksum_field() {
+    let message = b"8=FIX.4.2\x019=12\x0135=0\x0158=\x0110=\x0110=025\x01";
+
+    assert!(validate(message).unwrap());
+  }
+}
@@ -0,0 +1,190 @@
+use std::io;
+
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+use super::{FIXMessage, FIXFrameEvent, DEFAULT_MAX_MESSAGE_LENGTH, generate_bytes};
+use super::decoder::{FrameBuffer, decode_frame};
+
+impl FrameBuffer for BytesMut {
+  fn bytes(&self) -> &[u8] {
+    self
+  }
+
+  fn take(&mut self, length: usize) -> Vec<u8> {
+    self.split_to(length).to_vec()
+  }
+}
+
+/// This structure frames FIX messages for `tokio_util::codec::Framed`,
+/// available with `tokio` feature
+///
+/// Decoder finds message boundaries and validates messages the same way
+/// `FIXFrameDecoder` does and produces `FIXMessage`s. Skipped bytes and framed
+/// messages which do not pass validation are dropped, so that garbled message
+/// does not end the stream, incomplete message at the end of stream is
+/// reported as `io::ErrorKind::UnexpectedEof` error, use `FIXFrameDecoder` to
+/// get skipped and invalid bytes. Encoder validates and generates messages
+/// with `generate_bytes`, messages which do not pass validation are reported
+/// as `io::ErrorKind::InvalidInput` errors.
+///
+/// # Examples
+///
+/// ```
+/// extern crate fix_message;
+/// extern crate futures;
+/// extern crate tokio;
+/// extern crate tokio_util;
+///
+/// # fn main() {
+/// use fix_message::*;
+/// use futures::executor::block_on;
+/// use futures::{SinkExt, StreamExt};
+/// use tokio_util::codec::Framed;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+/// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+///
+/// let (client, server) = tokio::io::duplex(1024);
+/// let mut client = Framed::new(client, FIXCodec::new());
+/// let mut server = Framed::new(server, FIXCodec::new());
+///
+/// block_on(client.send(fix_message.clone())).unwrap();
+///
+/// match block_on(server.next()) {
+///   Some(Ok(message)) => assert_eq!(message, fix_message),
+///   result => panic!("Unexpected result {:?}", result),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FIXCodec {
+  max_message_length: usize,
+}
+
+/// Alias of `FIXCodec`
+pub type FixCodec = FIXCodec;
+
+impl FIXCodec {
+  /// Creates codec which accepts messages up to `DEFAULT_MAX_MESSAGE_LENGTH`
+  pub fn new() -> FIXCodec {
+    FIXCodec::with_max_message_length(DEFAULT_MAX_MESSAGE_LENGTH)
+  }
+
+  /// Creates codec which accepts messages up to `max_message_length` bytes
+  pub fn with_max_message_length(max_message_length: usize) -> FIXCodec {
+    FIXCodec { max_message_length }
+  }
+}
+
+impl Default for FIXCodec {
+  fn default() -> FIXCodec {
+    FIXCodec::new()
+  }
+}
+
+impl Decoder for FIXCodec {
+  type Item = FIXMessage;
+  type Error = io::Error;
+
+  fn decode(&mut self, src: &mut BytesMut) -> Result<Option<FIXMessage>, io::Error> {
+    loop {
+      match decode_frame(src, self.max_message_length) {
+        Some(FIXFrameEvent::Message(message)) => return Ok(Some(message)),
+        Some(FIXFrameEvent::Skipped(_)) | Some(FIXFrameEvent::Invalid(_, _)) => {},
+        None => return Ok(None),
+      }
+    }
+  }
+
+  fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<FIXMessage>, io::Error> {
+    match self.decode(src)? {
+      None if !src.is_empty() => {
+        src.clear();
+        Err(io::Error::new(io::ErrorKind::UnexpectedEof, "Incomplete FIX message at the end of stream"))
+      },
+      message => Ok(message),
+    }
+  }
+}
+
+impl Encoder<FIXMessage> for FIXCodec {
+  type Error = io::Error;
+
+  fn encode(&mut self, item: FIXMessage, dst: &mut BytesMut) -> Result<(), io::Error> {
+    let message = generate_bytes(&item)
+      .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    dst.extend_from_slice(&message);
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::{FIXMessageLocatedError, NotAllRequiredFieldsFound, parse_bytes};
+  use super::super::test_helpers::brew_heartbeat;
+
+  #[test]
+  fn it_should_encode_fix_message() {
+    let mut buffer = BytesMut::new();
+    FIXCodec::new().encode(brew_heartbeat(vec![]), &mut buffer).unwrap();
+
+    assert_eq!(&buffer[..], &generate_bytes(&brew_heartbeat(vec![])).unwrap()[..]);
+  }
+
+  #[test]
+  fn it_should_complain_when_encoding_invalid_fix_message() {
+    let mut message = brew_heartbeat(vec![]);
+    message.data.remove(1);
+    let mut buffer = BytesMut::new();
+
+    let err = FIXCodec::new().encode(message, &mut buffer).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(err.into_inner().unwrap().downcast::<FIXMessageLocatedError>().unwrap().error,
+      NotAllRequiredFieldsFound);
+    assert!(buffer.is_empty());
+  }
+
+  #[test]
+  fn it_should_decode_fix_messages() {
+    let message = generate_bytes(&brew_heartbeat(vec![])).unwrap();
+    let mut buffer = BytesMut::new();
+    buffer.extend_from_slice(b"xx");
+    buffer.extend_from_slice(&message);
+    buffer.extend_from_slice(&message[..10]);
+    let mut codec = FIXCodec::new();
+
+    assert_eq!(codec.decode(&mut buffer).unwrap(), Some(parse_bytes(&message).unwrap()));
+    assert_eq!(codec.decode(&mut buffer).unwrap(), None);
+    assert_eq!(&buffer[..], &message[..10]);
+  }
+
+  #[test]
+  fn it_should_skip_invalid_fix_message() {
+    let message = generate_bytes(&brew_heartbeat(vec![])).unwrap();
+    let mut invalid_message = message.clone();
+    let checksum_start = invalid_message.len() - 4;
+    invalid_message[checksum_start] = b'9';
+    let mut buffer = BytesMut::new();
+    buffer.extend_from_slice(&invalid_message);
+    buffer.extend_from_slice(&message);
+    let mut codec = FIXCodec::new();
+
+    assert_eq!(codec.decode(&mut buffer).unwrap(), Some(parse_bytes(&message).unwrap()));
+    assert!(buffer.is_empty());
+  }
+
+  #[test]
+  fn it_should_complain_about_incomplete_message_at_eof() {
+    let message = generate_bytes(&brew_heartbeat(vec![])).unwrap();
+    let mut buffer = BytesMut::new();
+    buffer.extend_from_slice(&message[..10]);
+    let mut codec = FIXCodec::new();
+
+    assert_eq!(codec.decode_eof(&mut buffer).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(codec.decode_eof(&mut buffer).unwrap(), None);
+  }
+}
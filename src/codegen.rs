@@ -0,0 +1,507 @@
+use std::ascii;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+use super::fix_message_fields::{BEGIN_STRING, BODY_LENGTH, CHECK_SUM, MSG_TYPE};
+use super::{ApplVerId, DataDictionary, FIXDataType, FieldDefinition, FixVersion, GroupSpec, MemberDefinition};
+
+// Fields which are not members of structures: framing fields are added by
+// generator and message type is implied by the structure
+const IMPLIED_FIELDS: [u32; 4] = [BEGIN_STRING, BODY_LENGTH, MSG_TYPE, CHECK_SUM];
+
+const HEADER_NAME: &str = "StandardHeader";
+
+const KEYWORDS: [&str; 52] = ["abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+  "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+  "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "self", "static",
+  "struct", "super", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+  "while", "yield", "union"];
+
+enum StructKind {
+  Header,
+  Message(String),
+  Group(u32),
+}
+
+enum Member {
+  Field { tag: u32, name: String, ident: String, rust_type: String, required: bool },
+  Group { tag: u32, name: String, ident: String, struct_name: String, required: bool },
+}
+
+struct StructDefinition {
+  name: String,
+  kind: StructKind,
+  members: Vec<Member>,
+}
+
+/// This structure generates Rust source code of typed message structures from
+/// data dictionary
+///
+/// For every message of the dictionary the code contains a structure with a
+/// field per member of the message, members of components are inlined and
+/// repeating groups become vectors of entry structures. Structures implement
+/// `TryFrom<&FIXMessage>` and `Into<FIXMessage>`, so messages are read with
+/// `parse` and written with `generate` as usual. The standard header becomes
+/// `StandardHeader` structure shared by messages, the trailer is left to
+/// generator.
+///
+/// Fields with enumerated values are represented by enums named after the
+/// field, e.g. `Side`, other fields by types of the crate:
+///
+/// | Data type                                   | Rust type      |
+/// |---------------------------------------------|----------------|
+/// | `int`                                       | `i64`          |
+/// | `Length`, `NumInGroup`, `SeqNum`, `TagNum`  | `u64`          |
+/// | `DayOfMonth`                                | `u32`          |
+/// | `float`, `Qty`, `Price`, `Amt`, ...         | `FixDecimal`   |
+/// | `char`                                      | `char`         |
+/// | `Boolean`                                   | `bool`         |
+/// | `MultipleValueString`, ...                  | `Vec<String>`  |
+/// | `MonthYear`, `UTCTimestamp`, ...            | `MonthYear`, `UtcTimestamp`, ... |
+/// | `data`, `XMLData`                           | `Vec<u8>`      |
+/// | other types                                 | `String`       |
+///
+/// Fields are optional unless they and all components they belong to are
+/// required. Generated code refers to the crate as `fix_message`, it is meant
+/// to be written by a build script, e.g. into `OUT_DIR`, or by `fix_codegen`
+/// binary and checked in.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let dictionary = DataDictionary::from_xml(r#"
+///   <fix major="4" minor="4">
+///     <header><field name="MsgType" required="Y"/></header>
+///     <messages>
+///       <message name="TestRequest" msgtype="1" msgcat="admin">
+///         <field name="TestReqID" required="Y"/>
+///       </message>
+///     </messages>
+///     <fields>
+///       <field number="35" name="MsgType" type="STRING"/>
+///       <field number="112" name="TestReqID" type="STRING"/>
+///     </fields>
+///   </fix>"#).unwrap();
+///
+/// let code = CodeGenerator::new(&dictionary).generate();
+///
+/// assert!(code.contains("pub struct TestRequest {"));
+/// assert!(code.contains("pub test_req_id: String,"));
+/// assert!(code.contains("impl <'a> TryFrom<&'a FIXMessage> for TestRequest {"));
+/// ```
+///
+/// Build script which generates structures of a few messages:
+///
+/// ```no_run
+/// extern crate fix_message;
+///
+/// use std::{env, fs};
+/// use std::path::Path;
+///
+/// use fix_message::*;
+///
+/// fn main() {
+///   let dictionary = DataDictionary::from_file("spec/FIX44.xml").unwrap();
+///   let code = CodeGenerator::new(&dictionary).with_messages(&["D", "8", "W"]).generate();
+///   fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("fix44.rs"), code).unwrap();
+/// }
+/// ```
+pub struct CodeGenerator<'a> {
+  dictionary: &'a DataDictionary,
+  msg_types: Option<Vec<String>>,
+}
+
+impl <'a> CodeGenerator<'a> {
+  pub fn new(dictionary: &'a DataDictionary) -> CodeGenerator<'a> {
+    CodeGenerator { dictionary, msg_types: None }
+  }
+
+  /// Limits generated messages to ones with `35 - MsgType` values, message
+  /// types unknown to the dictionary are ignored
+  pub fn with_messages(mut self, msg_types: &[&str]) -> CodeGenerator<'a> {
+    self.msg_types = Some(msg_types.iter().map(|msg_type| msg_type.to_string()).collect());
+    self
+  }
+
+  /// Returns generated source code, messages are ordered by name and enums by
+  /// tag, so the same dictionary always produces the same code
+  pub fn generate(&self) -> String {
+    let mut messages: Vec<_> = self.dictionary.messages()
+      .filter(|message| self.msg_types.as_ref().map_or(true, |msg_types| msg_types.contains(&message.msg_type)))
+      .collect();
+    messages.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut builder = Builder { dictionary: self.dictionary, structs: vec![], enums: BTreeMap::new() };
+    builder.build_struct(HEADER_NAME, StructKind::Header, self.dictionary.header());
+    for message in messages {
+      builder.build_struct(&message.name, StructKind::Message(message.msg_type.clone()), &message.members);
+    }
+
+    let mut code = String::new();
+    writeln!(code, "// Generated by fix_message `CodeGenerator` from {} data dictionary, do not edit", self.dictionary.version()).unwrap();
+    writeln!(code).unwrap();
+    writeln!(code, "use std::convert::TryFrom;").unwrap();
+    writeln!(code).unwrap();
+    writeln!(code, "use fix_message::*;").unwrap();
+    for definition in builder.enums.values() {
+      writeln!(code).unwrap();
+      write_enum(&mut code, definition);
+    }
+    for definition in &builder.structs {
+      writeln!(code).unwrap();
+      self.write_struct(&mut code, definition);
+    }
+    code
+  }
+
+  fn write_struct(&self, code: &mut String, definition: &StructDefinition) {
+    let name = &definition.name;
+    match definition.kind {
+      StructKind::Header => writeln!(code, "/// Standard header").unwrap(),
+      StructKind::Message(ref msg_type) => writeln!(code, "/// `{} - {}` message", msg_type, name).unwrap(),
+      StructKind::Group(tag) => writeln!(code, "/// Entry of `{} - {}` repeating group", tag,
+        self.dictionary.field(tag).map_or("", |field| &field.name)).unwrap(),
+    }
+    writeln!(code, "#[derive(PartialEq, Debug, Clone)]").unwrap();
+    writeln!(code, "pub struct {} {{", name).unwrap();
+    if let StructKind::Message(..) = definition.kind {
+      writeln!(code, "  pub header: {},", HEADER_NAME).unwrap();
+    }
+    for member in &definition.members {
+      match *member {
+        Member::Field { tag, name: ref field_name, ref ident, ref rust_type, required } => {
+          writeln!(code, "  /// `{} - {}`", tag, field_name).unwrap();
+          if required {
+            writeln!(code, "  pub {}: {},", ident, rust_type).unwrap();
+          } else {
+            writeln!(code, "  pub {}: Option<{}>,", ident, rust_type).unwrap();
+          }
+        },
+        Member::Group { tag, name: ref group_name, ref ident, ref struct_name, .. } => {
+          writeln!(code, "  /// `{} - {}`", tag, group_name).unwrap();
+          writeln!(code, "  pub {}: Vec<{}>,", ident, struct_name).unwrap();
+        },
+      }
+    }
+    writeln!(code, "}}").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(code, "impl {} {{", name).unwrap();
+    writeln!(code, "  /// Reads the structure from fields of message tree or group entry").unwrap();
+    writeln!(code, "  pub fn from_entry(entry: &FIXGroupEntry) -> Result<{}, FIXConversionError> {{", name).unwrap();
+    writeln!(code, "    Ok({} {{", name).unwrap();
+    if let StructKind::Message(..) = definition.kind {
+      writeln!(code, "      header: {}::from_entry(entry)?,", HEADER_NAME).unwrap();
+    }
+    for member in &definition.members {
+      match *member {
+        Member::Field { tag, ref ident, required, .. } => {
+          let getter = if required { "get_required" } else { "get_optional" };
+          writeln!(code, "      {}: entry.{}({})?,", ident, getter, tag).unwrap();
+        },
+        Member::Group { tag, ref ident, ref struct_name, .. } => {
+          writeln!(code, "      {}: match entry.group({}) {{", ident, tag).unwrap();
+          writeln!(code, "        Some(group) => group.iter().map({}::from_entry).collect::<Result<_, _>>()?,", struct_name).unwrap();
+          writeln!(code, "        None => vec![],").unwrap();
+          writeln!(code, "      }},").unwrap();
+        },
+      }
+    }
+    writeln!(code, "    }})").unwrap();
+    writeln!(code, "  }}").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(code, "  /// Appends fields of the structure to `data`").unwrap();
+    writeln!(code, "  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {{").unwrap();
+    if let StructKind::Message(..) = definition.kind {
+      writeln!(code, "    self.header.push_fields(data);").unwrap();
+    }
+    for member in &definition.members {
+      match *member {
+        Member::Field { tag, ref ident, required: true, .. } => {
+          writeln!(code, "    data.push(FIXMessageField::from_value({}, &self.{}));", tag, ident).unwrap();
+        },
+        Member::Field { tag, ref ident, required: false, .. } => {
+          writeln!(code, "    if let Some(ref value) = self.{} {{", ident).unwrap();
+          writeln!(code, "      data.push(FIXMessageField::from_value({}, value));", tag).unwrap();
+          writeln!(code, "    }}").unwrap();
+        },
+        Member::Group { tag, ref ident, required, .. } => {
+          let indent = if required { "    " } else { "      " };
+          if !required {
+            writeln!(code, "    if !self.{}.is_empty() {{", ident).unwrap();
+          }
+          writeln!(code, "{}data.push(FIXMessageField::from_value({}, &self.{}.len()));", indent, tag, ident).unwrap();
+          writeln!(code, "{}for entry in &self.{} {{", indent, ident).unwrap();
+          writeln!(code, "{}  entry.push_fields(data);", indent).unwrap();
+          writeln!(code, "{}}}", indent).unwrap();
+          if !required {
+            writeln!(code, "    }}").unwrap();
+          }
+        },
+      }
+    }
+    writeln!(code, "  }}").unwrap();
+    writeln!(code, "}}").unwrap();
+
+    if let StructKind::Message(ref msg_type) = definition.kind {
+      self.write_conversions(code, name, msg_type);
+    }
+  }
+
+  // FIX 5.0 and later messages are sent over `FIXT.1.1` session
+  fn begin_string(&self) -> &str {
+    match ApplVerId::from_version_name(self.dictionary.version()) {
+      Some(appl_ver_id) if appl_ver_id >= ApplVerId::Fix50 => FixVersion::Fixt11.as_str(),
+      _ => self.dictionary.version(),
+    }
+  }
+
+  fn write_conversions(&self, code: &mut String, name: &str, msg_type: &str) {
+    let literal = format!("{:?}", msg_type);
+    writeln!(code).unwrap();
+    writeln!(code, "impl <'a> TryFrom<&'a FIXMessage> for {} {{", name).unwrap();
+    writeln!(code, "  type Error = FIXConversionError;").unwrap();
+    writeln!(code).unwrap();
+    writeln!(code, "  fn try_from(message: &'a FIXMessage) -> Result<{}, FIXConversionError> {{", name).unwrap();
+    writeln!(code, "    if message.get_str({}) != Some({}) {{", MSG_TYPE, literal).unwrap();
+    writeln!(code, "      return Err(FIXConversionError::UnexpectedMsgType(message.get_str({}).unwrap_or(\"\").to_string()))", MSG_TYPE).unwrap();
+    writeln!(code, "    }}").unwrap();
+    let specs = self.dictionary.group_specs(msg_type);
+    if specs.is_empty() {
+      writeln!(code, "    {}::from_entry(&message.group_tree(&[])?)", name).unwrap();
+    } else {
+      writeln!(code, "    let specs = [").unwrap();
+      for spec in &specs {
+        writeln!(code, "      {},", spec_code(spec)).unwrap();
+      }
+      writeln!(code, "    ];").unwrap();
+      writeln!(code, "    {}::from_entry(&message.group_tree(&specs)?)", name).unwrap();
+    }
+    writeln!(code, "  }}").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(code, "impl From<{}> for FIXMessage {{", name).unwrap();
+    writeln!(code, "  fn from(message: {}) -> FIXMessage {{", name).unwrap();
+    writeln!(code, "    let mut data = vec![FIXMessageField::from_value({}, {})];", MSG_TYPE, literal).unwrap();
+    writeln!(code, "    message.push_fields(&mut data);").unwrap();
+    writeln!(code, "    FIXMessage {{ version: {:?}.to_string(), data }}", self.begin_string()).unwrap();
+    writeln!(code, "  }}").unwrap();
+    writeln!(code, "}}").unwrap();
+  }
+}
+
+// Collects structures and enums to generate, structures are ordered so that
+// entries of groups follow the structure they belong to
+struct Builder<'a> {
+  dictionary: &'a DataDictionary,
+  structs: Vec<StructDefinition>,
+  enums: BTreeMap<u32, &'a FieldDefinition>,
+}
+
+impl <'a> Builder<'a> {
+  fn build_struct(&mut self, name: &str, kind: StructKind, members: &'a [MemberDefinition]) {
+    let position = self.structs.len();
+    let mut struct_members = vec![];
+    self.collect_members(name, members, true, &mut HashSet::new(), &mut struct_members);
+    self.structs.insert(position, StructDefinition { name: name.to_string(), kind, members: struct_members });
+  }
+
+  fn collect_members(&mut self, owner: &str, members: &'a [MemberDefinition], required: bool,
+    seen: &mut HashSet<u32>, struct_members: &mut Vec<Member>) {
+    for member in members {
+      match *member {
+        MemberDefinition::Field { tag, required: field_required } => {
+          if IMPLIED_FIELDS.contains(&tag) || !seen.insert(tag) { continue }
+          let definition = match self.dictionary.field(tag) {
+            Some(definition) => definition,
+            None => continue,
+          };
+          struct_members.push(Member::Field {
+            tag,
+            name: definition.name.clone(),
+            ident: field_ident(&definition.name),
+            rust_type: self.rust_type(definition),
+            required: required && field_required,
+          });
+        },
+        MemberDefinition::Component { ref name, required: component_required } => {
+          if let Some(component) = self.dictionary.component(name) {
+            self.collect_members(owner, &component.members, required && component_required, seen, struct_members);
+          }
+        },
+        MemberDefinition::Group(ref group) => {
+          if !seen.insert(group.tag) { continue }
+          let group_name = self.dictionary.field(group.tag).map_or(format!("Group{}", group.tag), |field| field.name.clone());
+          let struct_name = format!("{}{}", owner, group_name);
+          self.build_struct(&struct_name, StructKind::Group(group.tag), &group.members);
+          struct_members.push(Member::Group {
+            tag: group.tag,
+            ident: field_ident(&group_name),
+            name: group_name,
+            struct_name,
+            required: required && group.required,
+          });
+        },
+      }
+    }
+  }
+
+  fn rust_type(&mut self, definition: &'a FieldDefinition) -> String {
+    let rust_type = match definition.data_type {
+      FIXDataType::Boolean => "bool",
+      FIXDataType::MultipleValueString |
+      FIXDataType::MultipleCharValue |
+      FIXDataType::MultipleStringValue => "Vec<String>",
+      _ if !definition.values.is_empty() => {
+        self.enums.insert(definition.tag, definition);
+        &definition.name
+      },
+      FIXDataType::Int => "i64",
+      FIXDataType::Length |
+      FIXDataType::NumInGroup |
+      FIXDataType::SeqNum |
+      FIXDataType::TagNum => "u64",
+      FIXDataType::DayOfMonth => "u32",
+      FIXDataType::Float |
+      FIXDataType::Qty |
+      FIXDataType::Price |
+      FIXDataType::PriceOffset |
+      FIXDataType::Amt |
+      FIXDataType::Percentage => "FixDecimal",
+      FIXDataType::Char => "char",
+      FIXDataType::MonthYear => "MonthYear",
+      FIXDataType::UtcTimestamp => "UtcTimestamp",
+      FIXDataType::UtcDateOnly => "UtcDateOnly",
+      FIXDataType::LocalMktDate => "LocalMktDate",
+      FIXDataType::Data |
+      FIXDataType::XmlData => "Vec<u8>",
+      _ => "String",
+    };
+    rust_type.to_string()
+  }
+}
+
+fn write_enum(code: &mut String, definition: &FieldDefinition) {
+  let name = &definition.name;
+  let mut variants: Vec<(String, String)> = vec![];
+  for value in &definition.values {
+    let mut variant = variant_ident(&value.description, &value.value);
+    if variants.iter().any(|(existing, _)| *existing == variant) {
+      variant = format!("{}{}", variant, variant_ident("", &value.value).trim_start_matches("Value"));
+    }
+    variants.push((variant, byte_literal(&value.value)));
+  }
+
+  writeln!(code, "/// Values of `{} - {}`", definition.tag, name).unwrap();
+  writeln!(code, "#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]").unwrap();
+  writeln!(code, "pub enum {} {{", name).unwrap();
+  for (variant, value) in &variants {
+    writeln!(code, "  /// `{}`", &value[2..value.len() - 1]).unwrap();
+    writeln!(code, "  {},", variant).unwrap();
+  }
+  writeln!(code, "}}").unwrap();
+  writeln!(code).unwrap();
+
+  writeln!(code, "impl FromFixValue for {} {{", name).unwrap();
+  writeln!(code, "  fn from_fix_value(value: &[u8]) -> Result<{}, FIXValueError> {{", name).unwrap();
+  writeln!(code, "    match value {{").unwrap();
+  for (variant, value) in &variants {
+    writeln!(code, "      {} => Ok({}::{}),", value, name, variant).unwrap();
+  }
+  writeln!(code, "      _ => Err(FIXValueError::InvalidEnumValue),").unwrap();
+  writeln!(code, "    }}").unwrap();
+  writeln!(code, "  }}").unwrap();
+  writeln!(code, "}}").unwrap();
+  writeln!(code).unwrap();
+
+  writeln!(code, "impl ToFixValue for {} {{", name).unwrap();
+  writeln!(code, "  fn to_fix_value(&self) -> Vec<u8> {{").unwrap();
+  writeln!(code, "    match *self {{").unwrap();
+  for (variant, value) in &variants {
+    writeln!(code, "      {}::{} => {}.to_vec(),", name, variant, value).unwrap();
+  }
+  writeln!(code, "    }}").unwrap();
+  writeln!(code, "  }}").unwrap();
+  writeln!(code, "}}").unwrap();
+}
+
+fn spec_code(spec: &GroupSpec) -> String {
+  let mut code = format!("GroupSpec::new({}, {}, vec!{:?})", spec.count_tag, spec.delimiter_tag, spec.member_tags);
+  for group in &spec.groups {
+    code.push_str(&format!(".with_group({})", spec_code(group)));
+  }
+  code
+}
+
+fn byte_literal(value: &str) -> String {
+  let escaped: String = value.bytes().flat_map(ascii::escape_default).map(char::from).collect();
+  format!("b\"{}\"", escaped)
+}
+
+// Field name in snake case, e.g. `ClOrdID` to `cl_ord_id`
+fn field_ident(name: &str) -> String {
+  let chars: Vec<char> = name.replace("IDs", "Ids").chars().collect();
+  let mut ident = String::new();
+  for (index, &c) in chars.iter().enumerate() {
+    if c.is_uppercase() && index > 0 {
+      let previous = chars[index - 1];
+      let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+      if previous.is_lowercase() || previous.is_ascii_digit() || (previous.is_uppercase() && next_is_lowercase) {
+        ident.push('_');
+      }
+    }
+    ident.extend(c.to_lowercase());
+  }
+  if KEYWORDS.contains(&ident.as_str()) { ident.push('_') }
+  ident
+}
+
+// Value description in camel case, e.g. `GOOD_TILL_CANCEL` to `GoodTillCancel`,
+// values without description are named after the value itself
+fn variant_ident(description: &str, value: &str) -> String {
+  let camel_case = |text: &str| -> String {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+      .filter(|part| !part.is_empty())
+      .map(|part| {
+        let rest = if part.chars().any(char::is_lowercase) { part[1..].to_string() } else { part[1..].to_lowercase() };
+        format!("{}{}", part[..1].to_uppercase(), rest)
+      })
+      .collect()
+  };
+
+  let ident = camel_case(description);
+  if ident.is_empty() {
+    format!("Value{}", camel_case(value))
+  } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+    format!("Value{}", ident)
+  } else {
+    ident
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_name_fields_in_snake_case() {
+    let cases = vec![("ClOrdID", "cl_ord_id"), ("SecurityIDSource", "security_id_source"), ("MDReqID", "md_req_id"),
+      ("NoPartyIDs", "no_party_ids"), ("NoMDEntryTypes", "no_md_entry_types"), ("Price2", "price2"), ("Type", "type_")];
+
+    for (name, ident) in cases {
+      assert_eq!(field_ident(name), ident);
+    }
+  }
+
+  #[test]
+  fn it_should_name_enum_variants_in_camel_case() {
+    assert_eq!(variant_ident("GOOD_TILL_CANCEL", "1"), "GoodTillCancel");
+    assert_eq!(variant_ident("NewOrderSingle", "D"), "NewOrderSingle");
+    assert_eq!(variant_ident("1_MINUTE", "1"), "Value1Minute");
+    assert_eq!(variant_ident("", "A1"), "ValueA1");
+  }
+}
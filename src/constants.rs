@@ -4,3 +4,10 @@ pub const FIX_MESSAGE_DELIMITER: char = '\x01';
 
 /// Standard FIX message field tag-value delimiter (code `0x3D`, `=`)
 pub const FIX_MESSAGE_FIELD_DELIMITER: char = '\x3D';
+
+/// Standard FIX message delimiter as a byte, see `FIX_MESSAGE_DELIMITER`
+pub const FIX_MESSAGE_DELIMITER_BYTE: u8 = b'\x01';
+
+/// Standard FIX message field tag-value delimiter as a byte, see
+/// `FIX_MESSAGE_FIELD_DELIMITER`
+pub const FIX_MESSAGE_FIELD_DELIMITER_BYTE: u8 = b'\x3D';
@@ -0,0 +1,377 @@
+use super::{FIXMessage, FIXMessageLocatedError, FIX_MESSAGE_DELIMITER_BYTE, parse_bytes};
+
+/// Default limit of a single FIX message length accepted by `FIXFrameDecoder`
+pub const DEFAULT_MAX_MESSAGE_LENGTH: usize = 1024 * 1024;
+
+const BEGIN_STRING_PATTERN: &[u8] = b"8=FIX";
+const BODY_LENGTH_PATTERN: &[u8] = b"9=";
+const CHECK_SUM_PATTERN: &[u8] = b"10=";
+// `10=` + 3 digits + delimiter
+const TRAILER_LENGTH: usize = 7;
+const MAX_BEGIN_STRING_LENGTH: usize = 32;
+const MAX_BODY_LENGTH_DIGITS: usize = 9;
+
+/// Result of looking up a frame at the start of a buffer
+#[derive(PartialEq, Debug)]
+pub(crate) enum Frame {
+  /// More bytes needed to find out
+  Incomplete,
+  /// Bytes that can not start a message and should be skipped
+  Garbage(usize),
+  /// Bytes framing a message, from `8 - BeginString` to `10 - CheckSum` inclusive
+  Message(usize),
+}
+
+fn is_prefix_of(bytes: &[u8], pattern: &[u8]) -> bool {
+  pattern.starts_with(bytes)
+}
+
+fn find_begin_string(buffer: &[u8]) -> Option<usize> {
+  buffer.windows(BEGIN_STRING_PATTERN.len()).position(|window| window == BEGIN_STRING_PATTERN)
+}
+
+// Number of trailing bytes that may turn into `BeginString` when more bytes come
+fn begin_string_tail_length(buffer: &[u8]) -> usize {
+  (1..BEGIN_STRING_PATTERN.len()).rev()
+    .find(|&length| buffer.len() >= length && is_prefix_of(&buffer[buffer.len() - length..], BEGIN_STRING_PATTERN))
+    .unwrap_or(0)
+}
+
+// Returns position of the first byte after the delimiter of a field, which
+// starts at `start` and is not longer than `max_length`
+fn find_field_end(buffer: &[u8], start: usize, max_length: usize) -> Option<Result<usize, ()>> {
+  let field = &buffer[start..];
+  match field.iter().take(max_length + 1).position(|&byte| byte == FIX_MESSAGE_DELIMITER_BYTE) {
+    Some(delimiter_index) => Some(Ok(start + delimiter_index + 1)),
+    None if field.len() > max_length => Some(Err(())),
+    None => None,
+  }
+}
+
+/// Looks up a frame at the start of `buffer` using `8 - BeginString` and
+/// `9 - BodyLength` fields of the standard header
+pub(crate) fn next_frame(buffer: &[u8], max_message_length: usize) -> Frame {
+  match find_begin_string(buffer) {
+    Some(0) => (),
+    Some(begin_string_start) => return Frame::Garbage(begin_string_start),
+    None => {
+      let garbage_length = buffer.len() - begin_string_tail_length(buffer);
+      if garbage_length > 0 { return Frame::Garbage(garbage_length) }
+      return Frame::Incomplete
+    },
+  }
+
+  let body_length_start = match find_field_end(buffer, 0, MAX_BEGIN_STRING_LENGTH) {
+    Some(Ok(body_length_start)) => body_length_start,
+    Some(Err(())) => return Frame::Garbage(1),
+    None => return Frame::Incomplete,
+  };
+
+  let rest = &buffer[body_length_start..];
+  if rest.len() < BODY_LENGTH_PATTERN.len() {
+    if is_prefix_of(rest, BODY_LENGTH_PATTERN) { return Frame::Incomplete }
+    return Frame::Garbage(1)
+  }
+  if !rest.starts_with(BODY_LENGTH_PATTERN) { return Frame::Garbage(1) }
+
+  let body_length_value_start = body_length_start + BODY_LENGTH_PATTERN.len();
+  let body_start = match find_field_end(buffer, body_length_value_start, MAX_BODY_LENGTH_DIGITS) {
+    Some(Ok(body_start)) => body_start,
+    Some(Err(())) => return Frame::Garbage(1),
+    None => {
+      if buffer[body_length_value_start..].iter().all(u8::is_ascii_digit) { return Frame::Incomplete }
+      return Frame::Garbage(1)
+    },
+  };
+
+  let body_length_value = &buffer[body_length_value_start..body_start - 1];
+  if body_length_value.is_empty() || !body_length_value.iter().all(u8::is_ascii_digit) { return Frame::Garbage(1) }
+  let body_length: usize = String::from_utf8_lossy(body_length_value).parse().unwrap_or(usize::MAX);
+
+  let message_length = match body_start.checked_add(body_length).and_then(|end| end.checked_add(TRAILER_LENGTH)) {
+    Some(message_length) if message_length <= max_message_length => message_length,
+    _ => return Frame::Garbage(1),
+  };
+  if buffer.len() < message_length { return Frame::Incomplete }
+
+  let trailer = &buffer[message_length - TRAILER_LENGTH..message_length];
+  if !trailer.starts_with(CHECK_SUM_PATTERN) || trailer[TRAILER_LENGTH - 1] != FIX_MESSAGE_DELIMITER_BYTE {
+    return Frame::Garbage(1)
+  }
+
+  Frame::Message(message_length)
+}
+
+/// Buffer of bytes which frames are decoded from
+pub(crate) trait FrameBuffer {
+  fn bytes(&self) -> &[u8];
+  fn take(&mut self, length: usize) -> Vec<u8>;
+}
+
+/// Bytes fed to `FIXFrameDecoder` with position of the first byte which is not
+/// taken yet, so that taking a frame does not move the rest of the buffer
+#[derive(Debug, Default)]
+struct ReadBuffer {
+  bytes: Vec<u8>,
+  position: usize,
+}
+
+impl ReadBuffer {
+  fn extend(&mut self, bytes: &[u8]) {
+    // Taken bytes are dropped once they are at least a half of the buffer, so
+    // that every byte is moved a constant number of times on average
+    if self.position > 0 && self.position * 2 >= self.bytes.len() {
+      self.bytes.drain(..self.position);
+      self.position = 0;
+    }
+    self.bytes.extend_from_slice(bytes);
+  }
+}
+
+impl FrameBuffer for ReadBuffer {
+  fn bytes(&self) -> &[u8] {
+    &self.bytes[self.position..]
+  }
+
+  fn take(&mut self, length: usize) -> Vec<u8> {
+    let taken = self.bytes[self.position..self.position + length].to_vec();
+    self.position += length;
+    taken
+  }
+}
+
+/// Takes the next event from the start of `buffer`, consecutive skipped bytes
+/// are reported as a single event
+pub(crate) fn decode_frame<B: FrameBuffer>(buffer: &mut B, max_message_length: usize) -> Option<FIXFrameEvent> {
+  let mut skipped = Vec::new();
+  loop {
+    match next_frame(buffer.bytes(), max_message_length) {
+      Frame::Garbage(length) => skipped.extend(buffer.take(length)),
+      _ if !skipped.is_empty() => return Some(FIXFrameEvent::Skipped(skipped)),
+      Frame::Incomplete => return None,
+      Frame::Message(length) => {
+        let frame = buffer.take(length);
+        return Some(match parse_bytes(&frame) {
+          Ok(message) => FIXFrameEvent::Message(message),
+          Err(err) => FIXFrameEvent::Invalid(frame, err),
+        })
+      },
+    }
+  }
+}
+
+/// Event produced by `FIXFrameDecoder`
+#[derive(PartialEq, Debug)]
+pub enum FIXFrameEvent {
+  /// Complete and valid FIX message
+  Message(FIXMessage),
+  /// Bytes skipped while looking for the start of the next message
+  Skipped(Vec<u8>),
+  /// Bytes framed as a message which did not pass validation and the reason
+  Invalid(Vec<u8>, FIXMessageLocatedError),
+}
+
+/// This structure decodes FIX messages from a stream of bytes
+///
+/// Bytes are accepted in chunks of any size via `feed`, message boundaries are
+/// found using `8 - BeginString` and `9 - BodyLength` fields of the standard
+/// header. Complete messages are validated with `parse_bytes` and returned by
+/// `decode`, the remainder is kept buffered until more bytes come.
+///
+/// Bytes that can not be a start of a message (garbage before
+/// `8 - BeginString`, header with invalid `9 - BodyLength`, message without
+/// `10 - CheckSum` at the declared length, message longer than allowed) are
+/// skipped until the next `8 - BeginString` is found and reported as
+/// `FIXFrameEvent::Skipped`.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+/// let (head, tail) = message.as_bytes().split_at(40);
+///
+/// let mut decoder = FIXFrameDecoder::new();
+/// decoder.feed(b"garbage");
+/// decoder.feed(head);
+/// assert_eq!(decoder.decode(), Some(FIXFrameEvent::Skipped(b"garbage".to_vec())));
+/// assert_eq!(decoder.decode(), None);
+///
+/// decoder.feed(tail);
+/// decoder.feed(head);
+/// assert_eq!(decoder.decode(), Some(FIXFrameEvent::Message(parse(&message).unwrap())));
+/// assert_eq!(decoder.decode(), None);
+/// assert_eq!(decoder.buffer(), head);
+/// ```
+#[derive(Debug)]
+pub struct FIXFrameDecoder {
+  buffer: ReadBuffer,
+  max_message_length: usize,
+}
+
+impl FIXFrameDecoder {
+  /// Creates decoder which accepts messages up to `DEFAULT_MAX_MESSAGE_LENGTH`
+  pub fn new() -> FIXFrameDecoder {
+    FIXFrameDecoder::with_max_message_length(DEFAULT_MAX_MESSAGE_LENGTH)
+  }
+
+  /// Creates decoder which accepts messages up to `max_message_length` bytes
+  pub fn with_max_message_length(max_message_length: usize) -> FIXFrameDecoder {
+    FIXFrameDecoder { buffer: ReadBuffer::default(), max_message_length }
+  }
+
+  /// Appends bytes to the decoder buffer
+  pub fn feed(&mut self, bytes: &[u8]) {
+    self.buffer.extend(bytes);
+  }
+
+  /// Returns buffered bytes which are not decoded yet
+  pub fn buffer(&self) -> &[u8] {
+    self.buffer.bytes()
+  }
+
+  /// Returns the next event or `None` when more bytes needed
+  pub fn decode(&mut self) -> Option<FIXFrameEvent> {
+    decode_frame(&mut self.buffer, self.max_message_length)
+  }
+}
+
+impl Default for FIXFrameDecoder {
+  fn default() -> FIXFrameDecoder {
+    FIXFrameDecoder::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::parse;
+  use super::super::test_helpers::brew_message_bytes;
+
+  fn brew_heartbeat() -> Vec<u8> {
+    brew_message_bytes(vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+      "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"])
+  }
+
+  fn parse_heartbeat() -> FIXFrameEvent {
+    FIXFrameEvent::Message(parse_bytes(&brew_heartbeat()).unwrap())
+  }
+
+  #[test]
+  fn it_should_find_message_frame() {
+    let message = brew_heartbeat();
+
+    assert_eq!(next_frame(&message, DEFAULT_MAX_MESSAGE_LENGTH), Frame::Message(message.len()));
+    assert_eq!(next_frame(&message[..message.len() - 1], DEFAULT_MAX_MESSAGE_LENGTH), Frame::Incomplete);
+    assert_eq!(next_frame(&message[..12], DEFAULT_MAX_MESSAGE_LENGTH), Frame::Incomplete);
+    assert_eq!(next_frame(&message[..3], DEFAULT_MAX_MESSAGE_LENGTH), Frame::Incomplete);
+    assert_eq!(next_frame(b"", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Incomplete);
+  }
+
+  #[test]
+  fn it_should_find_garbage() {
+    assert_eq!(next_frame(b"abc8=FIX", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(3));
+    assert_eq!(next_frame(b"abc8=F", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(3));
+    assert_eq!(next_frame(b"abcdef", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(6));
+    assert_eq!(next_frame(b"8=FIX.4.2\x0135=0\x01", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(1));
+    assert_eq!(next_frame(b"8=FIX.4.2\x019=7A\x01", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(1));
+    assert_eq!(next_frame(b"8=FIX.4.2\x019=\x01", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(1));
+    assert_eq!(next_frame(b"8=FIX.4.2\x019=1234567890", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(1));
+    assert_eq!(next_frame(b"8=FIX.4.2\x019=5\x0135=0\x0158=abcd", DEFAULT_MAX_MESSAGE_LENGTH), Frame::Garbage(1));
+  }
+
+  #[test]
+  fn it_should_skip_message_longer_than_allowed() {
+    let message = brew_heartbeat();
+
+    assert_eq!(next_frame(&message, message.len() - 1), Frame::Garbage(1));
+  }
+
+  #[test]
+  fn it_should_decode_message_fed_in_fragments() {
+    let message = brew_heartbeat();
+    let mut decoder = FIXFrameDecoder::new();
+
+    for byte in &message[..message.len() - 1] {
+      decoder.feed(&[*byte]);
+      assert_eq!(decoder.decode(), None);
+    }
+    decoder.feed(&message[message.len() - 1..]);
+
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    assert_eq!(decoder.decode(), None);
+    assert!(decoder.buffer().is_empty());
+  }
+
+  #[test]
+  fn it_should_decode_concatenated_messages() {
+    let message = brew_heartbeat();
+    let mut decoder = FIXFrameDecoder::new();
+    decoder.feed(&message);
+    decoder.feed(&message);
+    decoder.feed(&message[..20]);
+
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    assert_eq!(decoder.decode(), None);
+    assert_eq!(decoder.buffer(), &message[..20]);
+  }
+
+  #[test]
+  fn it_should_drop_taken_bytes_when_more_bytes_fed() {
+    let message = brew_heartbeat();
+    let mut decoder = FIXFrameDecoder::new();
+    decoder.feed(&[&message[..], &message[..], &message[..20]].concat());
+
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    decoder.feed(&message[20..40]);
+    assert_eq!(decoder.buffer.position, message.len());
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    decoder.feed(&message[40..]);
+    assert_eq!(decoder.buffer.position, 0);
+    assert_eq!(decoder.buffer.bytes, message);
+
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    assert!(decoder.buffer().is_empty());
+  }
+
+  #[test]
+  fn it_should_report_skipped_bytes_and_resync() {
+    let message = brew_heartbeat();
+    let mut decoder = FIXFrameDecoder::new();
+    decoder.feed(b"xx");
+    decoder.feed(&message[..30]);
+    decoder.feed(&message);
+
+    let mut skipped = b"xx".to_vec();
+    skipped.extend_from_slice(&message[..30]);
+    assert_eq!(decoder.decode(), Some(FIXFrameEvent::Skipped(skipped)));
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+    assert_eq!(decoder.decode(), None);
+  }
+
+  #[test]
+  fn it_should_report_invalid_message() {
+    let message = brew_message_bytes(vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+      "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=231"]);
+    let mut decoder = FIXFrameDecoder::new();
+    decoder.feed(&message);
+    decoder.feed(&brew_heartbeat());
+
+    assert_eq!(decoder.decode(), Some(FIXFrameEvent::Invalid(message.clone(), parse_bytes(&message).unwrap_err())));
+    assert_eq!(decoder.decode(), Some(parse_heartbeat()));
+  }
+
+  #[test]
+  fn it_should_decode_message_with_data_field_containing_begin_string() {
+    let message = brew_message_bytes(vec!["8=FIX.4.2", "9=67", "35=0", "49=BRKR", "56=INVMGR",
+      "34=235", "52=19980604-07:58:28", "95=7", "96=8=FIX\x01\x01", "10=135"]);
+    let mut decoder = FIXFrameDecoder::new();
+    decoder.feed(&message);
+
+    assert_eq!(decoder.decode(), Some(FIXFrameEvent::Message(parse(&String::from_utf8(message).unwrap()).unwrap())));
+  }
+}
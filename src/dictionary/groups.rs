@@ -0,0 +1,101 @@
+use super::super::fix_message_fields::MSG_TYPE;
+use super::super::{FIXGroupEntry, FIXGroupError, FIXMessage, GroupSpec};
+use super::{DataDictionary, GroupDefinition, MemberDefinition};
+
+impl DataDictionary {
+  /// Returns specifications of repeating groups of the header, the trailer
+  /// and the message with `msg_type`, including groups of components
+  ///
+  /// The first member of a group definition is the delimiter of its entries.
+  pub fn group_specs(&self, msg_type: &str) -> Vec<GroupSpec> {
+    let body = self.message(msg_type).map_or(&[][..], |message| &message.members[..]);
+    let mut specs = vec![];
+    for members in &[self.header(), body, self.trailer()] {
+      self.collect_group_specs(members, &mut specs);
+    }
+    specs
+  }
+
+  /// Returns fields of the message with repeating groups assembled into a
+  /// tree, see `FIXMessage::group_tree` and `group_specs`
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  ///
+  /// let dictionary = DataDictionary::from_xml(r#"
+  ///   <fix major="4" minor="4">
+  ///     <messages>
+  ///       <message name="MarketDataRequest" msgtype="V" msgcat="app">
+  ///         <group name="NoMDEntryTypes" required="Y">
+  ///           <field name="MDEntryType" required="Y"/>
+  ///         </group>
+  ///       </message>
+  ///     </messages>
+  ///     <fields>
+  ///       <field number="267" name="NoMDEntryTypes" type="NUMINGROUP"/>
+  ///       <field number="269" name="MDEntryType" type="CHAR"/>
+  ///     </fields>
+  ///   </fix>"#).unwrap();
+  ///
+  /// let fix_message = FIXMessage {
+  ///   version: "FIX.4.4".to_string(),
+  ///   data: vec![
+  ///     FIXMessageField { tag: 35, value: b"V".to_vec() },
+  ///     FIXMessageField { tag: 267, value: b"2".to_vec() },
+  ///     FIXMessageField { tag: 269, value: b"0".to_vec() },
+  ///     FIXMessageField { tag: 269, value: b"1".to_vec() },
+  ///   ]
+  /// };
+  ///
+  /// let tree = dictionary.group_tree(&fix_message).unwrap();
+  ///
+  /// assert_eq!(tree.group(267).unwrap().iter().map(|entry| entry.get_as::<char>(269).unwrap()).collect::<String>(), "01");
+  /// ```
+  pub fn group_tree<'a>(&self, message: &'a FIXMessage) -> Result<FIXGroupEntry<'a>, FIXGroupError> {
+    message.group_tree(&self.group_specs(message.get_str(MSG_TYPE).unwrap_or("")))
+  }
+
+  fn collect_group_specs(&self, members: &[MemberDefinition], specs: &mut Vec<GroupSpec>) {
+    for member in members {
+      match *member {
+        MemberDefinition::Field { .. } => {},
+        MemberDefinition::Component { ref name, .. } => {
+          if let Some(component) = self.component(name) {
+            self.collect_group_specs(&component.members, specs);
+          }
+        },
+        MemberDefinition::Group(ref group) => specs.push(self.group_spec(group)),
+      }
+    }
+  }
+
+  fn group_spec(&self, group: &GroupDefinition) -> GroupSpec {
+    let mut spec = GroupSpec::new(group.tag, self.first_tag(&group.members).unwrap_or(0), vec![]);
+    self.collect_group_members(&group.members, &mut spec);
+    spec
+  }
+
+  fn collect_group_members(&self, members: &[MemberDefinition], spec: &mut GroupSpec) {
+    for member in members {
+      match *member {
+        MemberDefinition::Field { tag, .. } => spec.member_tags.push(tag),
+        MemberDefinition::Component { ref name, .. } => {
+          if let Some(component) = self.component(name) {
+            self.collect_group_members(&component.members, spec);
+          }
+        },
+        MemberDefinition::Group(ref group) => spec.groups.push(self.group_spec(group)),
+      }
+    }
+  }
+
+  fn first_tag(&self, members: &[MemberDefinition]) -> Option<u32> {
+    match *members.first()? {
+      MemberDefinition::Field { tag, .. } => Some(tag),
+      MemberDefinition::Component { ref name, .. } => self.first_tag(&self.component(name)?.members),
+      MemberDefinition::Group(ref group) => Some(group.tag),
+    }
+  }
+}
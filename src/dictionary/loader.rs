@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+
+use roxmltree::{Document, Node};
+
+use super::super::DictionaryError;
+use super::{ComponentDefinition, DataDictionary, FIXDataType, FieldDefinition, FieldValueDefinition,
+  GroupDefinition, MemberDefinition, MessageCategory, MessageDefinition};
+
+// Loads definitions of QuickFIX XML specification, fields are loaded first,
+// so that members of other definitions can refer to them by name
+struct Loader<'a, 'input: 'a> {
+  document: &'a Document<'input>,
+  field_tags: HashMap<String, u32>,
+  component_references: Vec<(String, u32)>,
+}
+
+impl <'a, 'input> Loader<'a, 'input> {
+  fn line(&self, node: Node) -> u32 {
+    self.document.text_pos_at(node.range().start).row
+  }
+
+  fn attribute(&self, node: Node<'a, 'input>, name: &str) -> Result<&'a str, DictionaryError> {
+    node.attribute(name).ok_or_else(|| {
+      DictionaryError::MissingAttribute(node.tag_name().name().to_string(), name.to_string(), self.line(node))
+    })
+  }
+
+  fn invalid_attribute(&self, node: Node, name: &str, value: &str) -> DictionaryError {
+    DictionaryError::InvalidAttribute(name.to_string(), value.to_string(), self.line(node))
+  }
+
+  fn required(&self, node: Node<'a, 'input>) -> Result<bool, DictionaryError> {
+    match node.attribute("required") {
+      Some("Y") => Ok(true),
+      Some("N") | None => Ok(false),
+      Some(value) => Err(self.invalid_attribute(node, "required", value)),
+    }
+  }
+
+  fn field_tag(&self, node: Node<'a, 'input>) -> Result<u32, DictionaryError> {
+    let name = self.attribute(node, "name")?;
+    self.field_tags.get(name).cloned()
+      .ok_or_else(|| DictionaryError::UnknownField(name.to_string(), self.line(node)))
+  }
+
+  fn version(&self, root: Node<'a, 'input>) -> Result<String, DictionaryError> {
+    let protocol = root.attribute("type").unwrap_or("FIX");
+    let major = self.attribute(root, "major")?;
+    let minor = self.attribute(root, "minor")?;
+    match root.attribute("servicepack") {
+      None | Some("0") => Ok(format!("{}.{}.{}", protocol, major, minor)),
+      Some(servicepack) => Ok(format!("{}.{}.{}SP{}", protocol, major, minor, servicepack)),
+    }
+  }
+
+  fn fields(&mut self, section: Option<Node<'a, 'input>>) -> Result<HashMap<u32, FieldDefinition>, DictionaryError> {
+    let mut fields = HashMap::new();
+    for node in elements(section, "field") {
+      let number = self.attribute(node, "number")?;
+      let tag = number.parse().ok().filter(|&tag| tag > 0)
+        .ok_or_else(|| self.invalid_attribute(node, "number", number))?;
+      let name = self.attribute(node, "name")?.to_string();
+      let data_type = FIXDataType::from_name(self.attribute(node, "type")?);
+      let values = elements(Some(node), "value")
+        .map(|value| Ok(FieldValueDefinition {
+          value: self.attribute(value, "enum")?.to_string(),
+          description: value.attribute("description").unwrap_or("").to_string(),
+        }))
+        .collect::<Result<_, DictionaryError>>()?;
+
+      if fields.contains_key(&tag) || self.field_tags.contains_key(&name) {
+        return Err(DictionaryError::DuplicateField(name, self.line(node)))
+      }
+      self.field_tags.insert(name.clone(), tag);
+      fields.insert(tag, FieldDefinition { tag, name, data_type, values });
+    }
+    Ok(fields)
+  }
+
+  fn members(&mut self, parent: Option<Node<'a, 'input>>) -> Result<Vec<MemberDefinition>, DictionaryError> {
+    let mut members = vec![];
+    for node in parent.into_iter().flat_map(|parent| parent.children()).filter(Node::is_element) {
+      let required = self.required(node)?;
+      let member = match node.tag_name().name() {
+        "field" => MemberDefinition::Field { tag: self.field_tag(node)?, required },
+        "component" => {
+          let name = self.attribute(node, "name")?.to_string();
+          self.component_references.push((name.clone(), self.line(node)));
+          MemberDefinition::Component { name, required }
+        },
+        "group" => MemberDefinition::Group(GroupDefinition {
+          tag: self.field_tag(node)?,
+          required,
+          members: self.members(Some(node))?,
+        }),
+        _ => continue,
+      };
+      members.push(member);
+    }
+    Ok(members)
+  }
+
+  fn message(&mut self, node: Node<'a, 'input>) -> Result<MessageDefinition, DictionaryError> {
+    let category = match node.attribute("msgcat") {
+      Some("admin") => MessageCategory::Admin,
+      Some("app") | None => MessageCategory::App,
+      Some(value) => return Err(self.invalid_attribute(node, "msgcat", value)),
+    };
+    Ok(MessageDefinition {
+      name: self.attribute(node, "name")?.to_string(),
+      msg_type: self.attribute(node, "msgtype")?.to_string(),
+      category,
+      members: self.members(Some(node))?,
+    })
+  }
+}
+
+// Child elements of `parent` with `name`
+fn elements<'a, 'input>(parent: Option<Node<'a, 'input>>, name: &'static str) -> impl Iterator<Item = Node<'a, 'input>> {
+  parent.into_iter()
+    .flat_map(|parent| parent.children())
+    .filter(move |node| node.is_element() && node.tag_name().name() == name)
+}
+
+// Returns `true` when `members` contain component `name` directly or through
+// other components and groups, `path` holds components being expanded, so
+// cycles which do not pass through `name` are not followed
+fn contains_component(components: &HashMap<String, ComponentDefinition>, members: &[MemberDefinition],
+  name: &str, path: &mut Vec<String>) -> bool
+{
+  members.iter().any(|member| match *member {
+    MemberDefinition::Field { .. } => false,
+    MemberDefinition::Group(ref group) => contains_component(components, &group.members, name, path),
+    MemberDefinition::Component { name: ref member_name, .. } => {
+      if member_name == name { return true }
+      if path.contains(member_name) { return false }
+      path.push(member_name.clone());
+      let found = contains_component(components, &components[member_name].members, name, path);
+      path.pop();
+      found
+    },
+  })
+}
+
+pub(super) fn load(xml: &str) -> Result<DataDictionary, DictionaryError> {
+  let document = Document::parse(xml)?;
+  let root = document.root_element();
+  if root.tag_name().name() != "fix" {
+    return Err(DictionaryError::InvalidRootElement(root.tag_name().name().to_string()))
+  }
+  let section = |name| elements(Some(root), name).next();
+  let mut loader = Loader { document: &document, field_tags: HashMap::new(), component_references: vec![] };
+
+  let version = loader.version(root)?;
+  let fields = loader.fields(section("fields"))?;
+  let header = loader.members(section("header"))?;
+  let trailer = loader.members(section("trailer"))?;
+
+  let mut messages = HashMap::new();
+  for node in elements(section("messages"), "message") {
+    let message = loader.message(node)?;
+    messages.insert(message.msg_type.clone(), message);
+  }
+
+  let mut components = HashMap::new();
+  let mut component_lines = HashMap::new();
+  for node in elements(section("components"), "component") {
+    let name = loader.attribute(node, "name")?.to_string();
+    let members = loader.members(Some(node))?;
+    component_lines.insert(name.clone(), loader.line(node));
+    components.insert(name.clone(), ComponentDefinition { name, members });
+  }
+
+  if let Some((name, line)) = loader.component_references.iter().find(|(name, _)| !components.contains_key(name)) {
+    return Err(DictionaryError::UnknownComponent(name.clone(), *line))
+  }
+  for name in components.keys() {
+    if contains_component(&components, &components[name].members, name, &mut vec![]) {
+      return Err(DictionaryError::RecursiveComponent(name.clone(), component_lines[name]))
+    }
+  }
+
+  Ok(DataDictionary { version, fields, field_tags: loader.field_tags, header, trailer, messages, components })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn brew_dictionary(header: &str, fields: &str) -> String {
+    format!(r#"<fix type="FIXT" major="1" minor="1">
+  <header>{}</header>
+  <trailer><field name="CheckSum" required="Y"/></trailer>
+  <fields>
+    <field number="10" name="CheckSum" type="STRING"/>
+    {}
+  </fields>
+</fix>"#, header, fields)
+  }
+
+  #[test]
+  fn it_should_load_dictionary_without_messages_and_components() {
+    let dictionary = load(&brew_dictionary("", "")).unwrap();
+
+    assert_eq!(dictionary.version(), "FIXT.1.1");
+    assert_eq!(dictionary.trailer(), &[MemberDefinition::Field { tag: 10, required: true }]);
+    assert_eq!(dictionary.messages().count(), 0);
+  }
+
+  #[test]
+  fn it_should_load_service_pack_version() {
+    let dictionary = load(r#"<fix major="5" minor="0" servicepack="2"><fields/></fix>"#).unwrap();
+
+    assert_eq!(dictionary.version(), "FIX.5.0SP2");
+  }
+
+  #[test]
+  fn it_should_complain_about_invalid_dictionary() {
+    match load("<fix major=\"4\"") {
+      Err(DictionaryError::InvalidXml(..)) => {},
+      result => panic!("Unexpected result {:?}", result),
+    }
+    match load("<fixml/>") {
+      Err(DictionaryError::InvalidRootElement(ref name)) if name == "fixml" => {},
+      result => panic!("Unexpected result {:?}", result),
+    }
+    match load("<fix major=\"4\"/>") {
+      Err(DictionaryError::MissingAttribute(ref element, ref attribute, 1)) if element == "fix" && attribute == "minor" => {},
+      result => panic!("Unexpected result {:?}", result),
+    }
+  }
+
+  #[test]
+  fn it_should_complain_about_invalid_definitions() {
+    let cases = vec![
+      (brew_dictionary("", r#"<field number="0" name="Zero" type="INT"/>"#), "Invalid value of data dictionary attribute: `number=\"0\"` at line 6"),
+      (brew_dictionary("", r#"<field number="11" name="CheckSum" type="INT"/>"#), "Data dictionary field is defined more than once: `CheckSum` at line 6"),
+      (brew_dictionary(r#"<field name="BeginString"/>"#, ""), "Data dictionary field is not defined: `BeginString` at line 2"),
+      (brew_dictionary(r#"<field name="CheckSum" required="yes"/>"#, ""), "Invalid value of data dictionary attribute: `required=\"yes\"` at line 2"),
+      (brew_dictionary(r#"<component name="Hops"/>"#, ""), "Data dictionary component is not defined: `Hops` at line 2"),
+    ];
+
+    for (xml, message) in cases {
+      assert_eq!(load(&xml).unwrap_err().to_string(), message);
+    }
+  }
+
+  #[test]
+  fn it_should_complain_about_recursive_components() {
+    let fields = r#"<field number="627" name="NoHops" type="NUMINGROUP"/>"#;
+    let cases = vec![
+      (r#"<component name="Hops"><component name="Hops"/></component>"#, vec!["Hops"]),
+      (r#"<component name="Hops"><group name="NoHops"><component name="Hop"/></group></component>
+      <component name="Hop"><component name="Hops"/></component>"#, vec!["Hops", "Hop"]),
+    ];
+
+    for (components, names) in cases {
+      let xml = brew_dictionary("", fields).replace("</fix>", &format!("<components>{}</components></fix>", components));
+      match load(&xml) {
+        Err(DictionaryError::RecursiveComponent(ref name, _)) if names.contains(&name.as_str()) => {},
+        result => panic!("Unexpected result {:?}", result),
+      }
+    }
+  }
+}
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::DictionaryError;
+
+mod groups;
+mod loader;
+mod validation;
+
+/// Data type of FIX message field as it is named in the data dictionary
+///
+/// Deprecated type names of older FIX versions are mapped to their successors:
+/// `QUANTITY` to `Qty`, `UTCDATE` to `UtcDateOnly` and `TIME` to
+/// `UtcTimestamp`. Types unknown to the crate are kept as `Other`.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum FIXDataType {
+  Int,
+  Length,
+  NumInGroup,
+  SeqNum,
+  TagNum,
+  DayOfMonth,
+  Float,
+  Qty,
+  Price,
+  PriceOffset,
+  Amt,
+  Percentage,
+  Char,
+  Boolean,
+  String,
+  MultipleValueString,
+  MultipleCharValue,
+  MultipleStringValue,
+  Country,
+  Currency,
+  Exchange,
+  Language,
+  MonthYear,
+  UtcTimestamp,
+  UtcTimeOnly,
+  UtcDateOnly,
+  LocalMktDate,
+  TzTimeOnly,
+  TzTimestamp,
+  Data,
+  XmlData,
+  Other(String),
+}
+
+impl FIXDataType {
+  /// Returns data type by its name in the data dictionary, e.g. `PRICE`
+  pub fn from_name(name: &str) -> FIXDataType {
+    match name {
+      "INT" => FIXDataType::Int,
+      "LENGTH" => FIXDataType::Length,
+      "NUMINGROUP" => FIXDataType::NumInGroup,
+      "SEQNUM" => FIXDataType::SeqNum,
+      "TAGNUM" => FIXDataType::TagNum,
+      "DAYOFMONTH" => FIXDataType::DayOfMonth,
+      "FLOAT" => FIXDataType::Float,
+      "QTY" | "QUANTITY" => FIXDataType::Qty,
+      "PRICE" => FIXDataType::Price,
+      "PRICEOFFSET" => FIXDataType::PriceOffset,
+      "AMT" => FIXDataType::Amt,
+      "PERCENTAGE" => FIXDataType::Percentage,
+      "CHAR" => FIXDataType::Char,
+      "BOOLEAN" => FIXDataType::Boolean,
+      "STRING" => FIXDataType::String,
+      "MULTIPLEVALUESTRING" => FIXDataType::MultipleValueString,
+      "MULTIPLECHARVALUE" => FIXDataType::MultipleCharValue,
+      "MULTIPLESTRINGVALUE" => FIXDataType::MultipleStringValue,
+      "COUNTRY" => FIXDataType::Country,
+      "CURRENCY" => FIXDataType::Currency,
+      "EXCHANGE" => FIXDataType::Exchange,
+      "LANGUAGE" => FIXDataType::Language,
+      "MONTHYEAR" => FIXDataType::MonthYear,
+      "UTCTIMESTAMP" | "TIME" => FIXDataType::UtcTimestamp,
+      "UTCTIMEONLY" => FIXDataType::UtcTimeOnly,
+      "UTCDATEONLY" | "UTCDATE" => FIXDataType::UtcDateOnly,
+      "LOCALMKTDATE" => FIXDataType::LocalMktDate,
+      "TZTIMEONLY" => FIXDataType::TzTimeOnly,
+      "TZTIMESTAMP" => FIXDataType::TzTimestamp,
+      "DATA" => FIXDataType::Data,
+      "XMLDATA" => FIXDataType::XmlData,
+      _ => FIXDataType::Other(name.to_string()),
+    }
+  }
+}
+
+/// One of enumerated values of a field
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FieldValueDefinition {
+  /// Value as it appears in the message
+  pub value: String,
+  /// Description of the value, e.g. `LIMIT`
+  pub description: String,
+}
+
+/// Field definition of the data dictionary
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FieldDefinition {
+  /// Tag number of the field
+  pub tag: u32,
+  /// Name of the field, e.g. `OrdType`
+  pub name: String,
+  /// Data type of the field
+  pub data_type: FIXDataType,
+  /// Enumerated values of the field, empty when any value of the data type is
+  /// allowed
+  pub values: Vec<FieldValueDefinition>,
+}
+
+/// Repeating group definition, a group is identified by its `NumInGroup` field
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct GroupDefinition {
+  /// Tag of `NumInGroup` field which holds the number of group entries
+  pub tag: u32,
+  pub required: bool,
+  /// Members of each group entry, the first member starts an entry
+  pub members: Vec<MemberDefinition>,
+}
+
+/// Member of message, component, group, header or trailer definition
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum MemberDefinition {
+  Field { tag: u32, required: bool },
+  Component { name: String, required: bool },
+  Group(GroupDefinition),
+}
+
+impl MemberDefinition {
+  pub fn is_required(&self) -> bool {
+    match *self {
+      MemberDefinition::Field { required, .. } |
+      MemberDefinition::Component { required, .. } => required,
+      MemberDefinition::Group(ref group) => group.required,
+    }
+  }
+}
+
+/// Component definition of the data dictionary, a named block of members
+/// shared by messages
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ComponentDefinition {
+  pub name: String,
+  pub members: Vec<MemberDefinition>,
+}
+
+/// Category of message definition
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum MessageCategory {
+  /// Session level message
+  Admin,
+  /// Application level message
+  App,
+}
+
+/// Message definition of the data dictionary
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct MessageDefinition {
+  /// Name of the message, e.g. `NewOrderSingle`
+  pub name: String,
+  /// `35 - MsgType` value of the message
+  pub msg_type: String,
+  pub category: MessageCategory,
+  /// Members of message body
+  pub members: Vec<MemberDefinition>,
+}
+
+/// This structure describes a version of FIX protocol or a venue specific
+/// dialect of it: fields, messages, components, header and trailer
+///
+/// Dictionary is loaded from QuickFIX XML specification, e.g. `FIX42.xml`,
+/// `FIX44.xml` or `FIXT11.xml`. All field and component references are
+/// resolved when dictionary is loaded, so members of definitions refer to
+/// existing fields and components.
+///
+/// # Errors
+///
+/// Loading fails with `DictionaryError` when file can not be read, XML is not
+/// well-formed or definitions are inconsistent, e.g. message refers to a field
+/// which is not defined.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let dictionary = DataDictionary::from_xml(r#"
+///   <fix type="FIX" major="4" minor="2">
+///     <header><field name="BeginString" required="Y"/></header>
+///     <messages>
+///       <message name="Heartbeat" msgtype="0" msgcat="admin">
+///         <field name="TestReqID" required="N"/>
+///       </message>
+///     </messages>
+///     <trailer/>
+///     <components/>
+///     <fields>
+///       <field number="8" name="BeginString" type="STRING"/>
+///       <field number="112" name="TestReqID" type="STRING"/>
+///     </fields>
+///   </fix>"#).unwrap();
+///
+/// assert_eq!(dictionary.version(), "FIX.4.2");
+/// assert_eq!(dictionary.field_by_name("TestReqID").unwrap().tag, 112);
+/// assert_eq!(dictionary.message("0").unwrap().name, "Heartbeat");
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct DataDictionary {
+  version: String,
+  fields: HashMap<u32, FieldDefinition>,
+  field_tags: HashMap<String, u32>,
+  header: Vec<MemberDefinition>,
+  trailer: Vec<MemberDefinition>,
+  messages: HashMap<String, MessageDefinition>,
+  components: HashMap<String, ComponentDefinition>,
+}
+
+impl DataDictionary {
+  /// Loads dictionary from QuickFIX XML specification
+  pub fn from_xml(xml: &str) -> Result<DataDictionary, DictionaryError> {
+    loader::load(xml)
+  }
+
+  /// Loads dictionary from QuickFIX XML specification file
+  pub fn from_file<P: AsRef<Path>>(path: P) -> Result<DataDictionary, DictionaryError> {
+    DataDictionary::from_xml(&fs::read_to_string(path)?)
+  }
+
+  /// Returns FIX protocol version, e.g. `FIX.4.4`, `FIXT.1.1` or `FIX.5.0SP2`
+  pub fn version(&self) -> &str {
+    &self.version
+  }
+
+  /// Returns field definition by tag
+  pub fn field(&self, tag: u32) -> Option<&FieldDefinition> {
+    self.fields.get(&tag)
+  }
+
+  /// Returns field definition by name
+  pub fn field_by_name(&self, name: &str) -> Option<&FieldDefinition> {
+    self.field_tags.get(name).and_then(|tag| self.field(*tag))
+  }
+
+  /// Returns all field definitions in no particular order
+  pub fn fields(&self) -> impl Iterator<Item = &FieldDefinition> {
+    self.fields.values()
+  }
+
+  /// Returns members of the standard header
+  pub fn header(&self) -> &[MemberDefinition] {
+    &self.header
+  }
+
+  /// Returns members of the standard trailer
+  pub fn trailer(&self) -> &[MemberDefinition] {
+    &self.trailer
+  }
+
+  /// Returns message definition by `35 - MsgType` value
+  pub fn message(&self, msg_type: &str) -> Option<&MessageDefinition> {
+    self.messages.get(msg_type)
+  }
+
+  /// Returns all message definitions in no particular order
+  pub fn messages(&self) -> impl Iterator<Item = &MessageDefinition> {
+    self.messages.values()
+  }
+
+  /// Returns component definition by name
+  pub fn component(&self, name: &str) -> Option<&ComponentDefinition> {
+    self.components.get(name)
+  }
+
+  /// Returns all component definitions in no particular order
+  pub fn components(&self) -> impl Iterator<Item = &ComponentDefinition> {
+    self.components.values()
+  }
+}
@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet};
+
+use super::super::fix_message_fields::{BEGIN_STRING, BODY_LENGTH, CHECK_SUM, MSG_TYPE};
+use super::super::{DictionaryViolation, FIXMessage, FIXMessageField, FixDecimal, FromFixValue, LocalMktDate,
+  MonthYear, SessionRejectReason, UtcDateOnly, UtcTimestamp};
+use super::{DataDictionary, FIXDataType, FieldDefinition, MemberDefinition};
+
+// Fields which are added by generator and validated by parser
+const FRAMING_FIELDS: [u32; 3] = [BEGIN_STRING, BODY_LENGTH, CHECK_SUM];
+
+#[derive(PartialEq, Clone, Copy)]
+enum Section {
+  Header,
+  Body,
+  Trailer,
+}
+
+// Tags of members, including members of components and groups
+#[derive(Default)]
+struct MemberTags {
+  all: HashSet<u32>,
+  // Tags of group members, they may legally appear more than once
+  repeating: HashSet<u32>,
+}
+
+impl DataDictionary {
+  /// This function validates FIX message against the dictionary and returns
+  /// all violations found, each with `SessionRejectReason` to reject the
+  /// message with
+  ///
+  /// The following rules are checked:
+  /// - `35 - MsgType` is defined in the dictionary (`InvalidMsgType`)
+  /// - every tag is defined in the dictionary (`InvalidTagNumber`)
+  /// - body fields belong to the message definition
+  ///   (`TagNotDefinedForMessageType`)
+  /// - header fields come first and trailer fields come last
+  ///   (`TagSpecifiedOutOfRequiredOrder`)
+  /// - values are not empty (`TagSpecifiedWithoutValue`), match enumerated
+  ///   values of the field (`ValueIsIncorrect`) and its data type
+  ///   (`IncorrectDataFormat`)
+  /// - fields out of repeating groups appear once (`TagAppearsMoreThanOnce`)
+  /// - required fields of header, trailer and message are present
+  ///   (`RequiredTagMissing`), members of repeating groups are not checked
+  /// - repeating groups have as many entries as declared
+  ///   (`IncorrectNumInGroupCount`) and entries start with the delimiter
+  ///   (`RepeatingGroupFieldsOutOfOrder`), only the first invalid group is
+  ///   reported
+  ///
+  /// Fields `8 - BeginString`, `9 - BodyLength` and `10 - CheckSum` are not
+  /// required, because generator adds them, so messages can be validated
+  /// before they are generated as well as after they are parsed.
+  ///
+  /// Data types are checked for numeric, `char`, `Boolean`, `MonthYear`,
+  /// `UTCTimestamp`, `UTCDateOnly` and `LocalMktDate` fields, values of other
+  /// types are accepted as they are.
+  ///
+  /// # Errors
+  ///
+  /// Returns violations in order of fields they were found in, followed by
+  /// missing required fields.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  ///
+  /// let dictionary = DataDictionary::from_xml(r#"
+  ///   <fix major="4" minor="2">
+  ///     <header><field name="MsgType" required="Y"/></header>
+  ///     <messages>
+  ///       <message name="Logon" msgtype="A" msgcat="admin">
+  ///         <field name="HeartBtInt" required="Y"/>
+  ///       </message>
+  ///     </messages>
+  ///     <fields>
+  ///       <field number="35" name="MsgType" type="STRING"/>
+  ///       <field number="108" name="HeartBtInt" type="INT"/>
+  ///     </fields>
+  ///   </fix>"#).unwrap();
+  ///
+  /// let fix_message = FIXMessage {
+  ///   version: "FIX.4.2".to_string(),
+  ///   data: vec![
+  ///     FIXMessageField { tag: 35, value: b"A".to_vec() },
+  ///     FIXMessageField { tag: 108, value: b"thirty".to_vec() },
+  ///     FIXMessageField { tag: 58, value: b"hello".to_vec() },
+  ///   ]
+  /// };
+  ///
+  /// let violations = dictionary.validate(&fix_message).unwrap_err();
+  ///
+  /// assert_eq!(violations.iter().map(|violation| (violation.reason.code(), violation.tag)).collect::<Vec<_>>(),
+  ///   vec![(6, 108), (0, 58)]);
+  /// ```
+  pub fn validate(&self, message: &FIXMessage) -> Result<(), Vec<DictionaryViolation>> {
+    let mut violations = vec![];
+    let header = self.member_tags(&self.header);
+    let trailer = self.member_tags(&self.trailer);
+    let mut required = self.required_tags(&self.header);
+    required.extend(self.required_tags(&self.trailer));
+
+    let msg_type_index = message.data.iter().position(|field| field.tag == MSG_TYPE);
+    let body = match msg_type_index.map(|index| (index, self.message(&String::from_utf8_lossy(&message.data[index].value)))) {
+      Some((_, Some(definition))) => {
+        required.extend(self.required_tags(&definition.members));
+        Some(self.member_tags(&definition.members))
+      },
+      Some((field_index, None)) => {
+        violations.push(DictionaryViolation { reason: SessionRejectReason::InvalidMsgType, tag: MSG_TYPE, field_index: Some(field_index) });
+        None
+      },
+      None => None,
+    };
+
+    let mut section = Section::Header;
+    let mut occurrences: HashMap<u32, usize> = HashMap::new();
+    for (field_index, field) in message.data.iter().enumerate() {
+      let mut violation = |reason| violations.push(DictionaryViolation { reason, tag: field.tag, field_index: Some(field_index) });
+
+      let definition = match self.field(field.tag) {
+        Some(definition) => definition,
+        None => {
+          violation(SessionRejectReason::InvalidTagNumber);
+          continue
+        },
+      };
+
+      if section != Section::Header || !header.all.contains(&field.tag) {
+        if trailer.all.contains(&field.tag) {
+          section = Section::Trailer;
+        } else if section == Section::Trailer || header.all.contains(&field.tag) {
+          violation(SessionRejectReason::TagSpecifiedOutOfRequiredOrder);
+        } else {
+          section = Section::Body;
+          if body.as_ref().is_some_and(|body| !body.all.contains(&field.tag)) {
+            violation(SessionRejectReason::TagNotDefinedForMessageType);
+          }
+        }
+      }
+
+      let repeating = [&header, &trailer].iter().chain(body.as_ref().iter())
+        .any(|tags| tags.repeating.contains(&field.tag));
+      let occurrence = occurrences.entry(field.tag).or_insert(0);
+      *occurrence += 1;
+      if *occurrence == 2 && !repeating {
+        violation(SessionRejectReason::TagAppearsMoreThanOnce);
+      }
+
+      // Invalid MsgType is already reported
+      if body.is_none() && Some(field_index) == msg_type_index { continue }
+      if let Some(reason) = check_value(definition, field) {
+        violation(reason);
+      }
+    }
+
+    if let Err(err) = self.group_tree(message) {
+      let field_index = message.data.iter().position(|field| field.tag == err.tag());
+      violations.push(DictionaryViolation { reason: err.reject_reason(), tag: err.tag(), field_index });
+    }
+
+    let mut reported = HashSet::new();
+    let missing = required.into_iter()
+      .filter(|tag| !FRAMING_FIELDS.contains(tag) && !occurrences.contains_key(tag) && reported.insert(*tag))
+      .map(|tag| DictionaryViolation { reason: SessionRejectReason::RequiredTagMissing, tag, field_index: None });
+    violations.extend(missing);
+
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+  }
+
+  fn member_tags(&self, members: &[MemberDefinition]) -> MemberTags {
+    let mut tags = MemberTags::default();
+    self.collect_member_tags(members, false, &mut tags);
+    tags
+  }
+
+  fn collect_member_tags(&self, members: &[MemberDefinition], repeating: bool, tags: &mut MemberTags) {
+    for member in members {
+      match *member {
+        MemberDefinition::Field { tag, .. } => {
+          tags.all.insert(tag);
+          if repeating { tags.repeating.insert(tag); }
+        },
+        MemberDefinition::Component { ref name, .. } => {
+          if let Some(component) = self.component(name) {
+            self.collect_member_tags(&component.members, repeating, tags);
+          }
+        },
+        MemberDefinition::Group(ref group) => {
+          tags.all.insert(group.tag);
+          if repeating { tags.repeating.insert(group.tag); }
+          self.collect_member_tags(&group.members, true, tags);
+        },
+      }
+    }
+  }
+
+  // Tags of required fields out of repeating groups in order of definition
+  fn required_tags(&self, members: &[MemberDefinition]) -> Vec<u32> {
+    let mut tags = vec![];
+    for member in members.iter().filter(|member| member.is_required()) {
+      match *member {
+        MemberDefinition::Field { tag, .. } => tags.push(tag),
+        MemberDefinition::Component { ref name, .. } => {
+          if let Some(component) = self.component(name) {
+            tags.extend(self.required_tags(&component.members));
+          }
+        },
+        MemberDefinition::Group(ref group) => tags.push(group.tag),
+      }
+    }
+    tags
+  }
+}
+
+fn check_value(definition: &FieldDefinition, field: &FIXMessageField) -> Option<SessionRejectReason> {
+  if field.value.is_empty() { return Some(SessionRejectReason::TagSpecifiedWithoutValue) }
+  if !is_valid_format(&definition.data_type, &field.value) { return Some(SessionRejectReason::IncorrectDataFormat) }
+  if definition.values.is_empty() { return None }
+
+  let is_enumerated = |value: &[u8]| definition.values.iter().any(|enumerated| enumerated.value.as_bytes() == value);
+  let valid = match definition.data_type {
+    FIXDataType::MultipleValueString |
+    FIXDataType::MultipleCharValue |
+    FIXDataType::MultipleStringValue => field.value.split(|&byte| byte == b' ').all(is_enumerated),
+    _ => is_enumerated(&field.value),
+  };
+  if valid { None } else { Some(SessionRejectReason::ValueIsIncorrect) }
+}
+
+fn is_valid_format(data_type: &FIXDataType, value: &[u8]) -> bool {
+  match *data_type {
+    FIXDataType::Int => i64::from_fix_value(value).is_ok(),
+    FIXDataType::Length |
+    FIXDataType::NumInGroup |
+    FIXDataType::SeqNum |
+    FIXDataType::TagNum => u64::from_fix_value(value).is_ok(),
+    FIXDataType::DayOfMonth => u32::from_fix_value(value).is_ok_and(|day| (1..=31).contains(&day)),
+    FIXDataType::Float |
+    FIXDataType::Qty |
+    FIXDataType::Price |
+    FIXDataType::PriceOffset |
+    FIXDataType::Amt |
+    FIXDataType::Percentage => FixDecimal::from_fix_value(value).is_ok(),
+    FIXDataType::Char => char::from_fix_value(value).is_ok(),
+    FIXDataType::Boolean => bool::from_fix_value(value).is_ok(),
+    FIXDataType::MonthYear => MonthYear::from_fix_value(value).is_ok(),
+    FIXDataType::UtcTimestamp => UtcTimestamp::from_fix_value(value).is_ok(),
+    FIXDataType::UtcDateOnly => UtcDateOnly::from_fix_value(value).is_ok(),
+    FIXDataType::LocalMktDate => LocalMktDate::from_fix_value(value).is_ok(),
+    _ => true,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_check_value_formats() {
+    assert!(is_valid_format(&FIXDataType::Int, b"-5"));
+    assert!(!is_valid_format(&FIXDataType::SeqNum, b"-5"));
+    assert!(!is_valid_format(&FIXDataType::DayOfMonth, b"32"));
+    assert!(is_valid_format(&FIXDataType::Price, b"100.2500"));
+    assert!(!is_valid_format(&FIXDataType::Qty, b"1e3"));
+    assert!(!is_valid_format(&FIXDataType::Boolean, b"y"));
+    assert!(is_valid_format(&FIXDataType::Other("CUSTOM".to_string()), b"anything"));
+  }
+
+  #[test]
+  fn it_should_check_enumerated_values() {
+    let definition = FieldDefinition {
+      tag: 18,
+      name: "ExecInst".to_string(),
+      data_type: FIXDataType::MultipleCharValue,
+      values: ["1", "2", "G"].iter()
+        .map(|value| super::super::FieldValueDefinition { value: value.to_string(), description: String::new() })
+        .collect(),
+    };
+    let check = |value: &[u8]| check_value(&definition, &FIXMessageField { tag: 18, value: value.to_vec() });
+
+    assert_eq!(check(b"1 G"), None);
+    assert_eq!(check(b"1 X"), Some(SessionRejectReason::ValueIsIncorrect));
+    assert_eq!(check(b""), Some(SessionRejectReason::TagSpecifiedWithoutValue));
+  }
+}
@@ -1,43 +1,550 @@
 use std::error::Error;
 use std::fmt;
+use std::io;
+use std::num;
 use fix_checksum::FIXChecksumValidatorError;
+use roxmltree;
 use self::FIXMessageError::*;
 
+/// Errors of FIX message validation
+///
+/// Errors own their data, so they outlive validated message and can be sent
+/// across threads or boxed into `Box<dyn Error + Send + Sync>`.
 #[derive(PartialEq, Debug)]
-pub enum FIXMessageError <'a> {
+pub enum FIXMessageError {
   InvalidChecksum(FIXChecksumValidatorError),
   InvalidChecksumValue,
+  InvalidBodyLength(usize, usize),
+  InvalidBodyLengthFormat(num::ParseIntError),
+  /// Parser no longer returns this error, malformed fields are reported as
+  /// `MissingFieldSeparator`, `EmptyFieldTag`, `EmptyFieldValue` or
+  /// `NonNumericFieldTag`
+  #[deprecated(note = "use MissingFieldSeparator, EmptyFieldTag, EmptyFieldValue or NonNumericFieldTag")]
   InvalidFieldStructure,
-  InvalidFirstField(&'a str),
-  InvalidSecondField(&'a str),
-  InvalidThirdField(&'a str),
+  MissingFieldSeparator,
+  EmptyFieldTag,
+  EmptyFieldValue,
+  NonNumericFieldTag,
+  InvalidTagNumber,
+  InvalidFieldEncoding,
+  InvalidDataLengthFormat(num::ParseIntError),
+  DataLengthOverrun(usize),
+  DataLengthMismatch(usize),
+  InvalidFirstField(u32),
+  InvalidSecondField(u32),
+  InvalidThirdField(u32),
   NotAllRequiredFieldsFound,
   ExtraRequiredFieldFound,
+  UnknownBeginString(String),
+  FieldNotDefinedInVersion(u32),
+  MissingVersionRequiredField(u32),
+  InvalidApplVerID,
+  FieldOutOfOrder(u32),
+  MissingRequiredField(u32),
+  /// Generated field value contains `FIX_MESSAGE_DELIMITER`, which would split
+  /// it into several fields, use `data` field to send such value
+  ValueContainsDelimiter,
 }
 
-impl <'a> fmt::Display for FIXMessageError<'a> {
+impl fmt::Display for FIXMessageError {
+  #[allow(deprecated)]
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     match *self {
       InvalidChecksum(ref err) => write!(f, "{}: {}", self.description(), err),
-      InvalidFirstField(ref tag) |
-      InvalidSecondField(ref tag) |
-      InvalidThirdField(ref tag) => write!(f, "{}: {}", self.description(), tag),
+      InvalidBodyLength(declared, computed) =>
+        write!(f, "{}: declared {}, computed {}", self.description(), declared, computed),
+      InvalidBodyLengthFormat(ref err) |
+      InvalidDataLengthFormat(ref err) => write!(f, "{}: {}", self.description(), err),
+      DataLengthOverrun(length) |
+      DataLengthMismatch(length) => write!(f, "{}: {}", self.description(), length),
+      InvalidFirstField(tag) |
+      InvalidSecondField(tag) |
+      InvalidThirdField(tag) |
+      FieldNotDefinedInVersion(tag) |
+      MissingVersionRequiredField(tag) |
+      FieldOutOfOrder(tag) |
+      MissingRequiredField(tag) => write!(f, "{}: {}", self.description(), tag),
+      UnknownBeginString(ref version) => write!(f, "{}: `{}`", self.description(), version),
       _ => write!(f, "{}", self.description()),
     }
   }
 }
 
-impl <'a> Error for FIXMessageError<'a> {
+impl Error for FIXMessageError {
+  #[allow(deprecated)]
   fn description(&self) -> &str {
     match *self {
       InvalidChecksum(..) => "Invalid FIX message checksum",
       InvalidChecksumValue => "Invalid value of FIX message checksum",
+      InvalidBodyLength(..) => "Invalid value of FIX message body length",
+      InvalidBodyLengthFormat(..) => "Body length value invalid format",
       InvalidFieldStructure => "Invalid structure of FIX message field, should be <tag>=<value>",
+      MissingFieldSeparator => "FIX message field has no separator between tag and value",
+      EmptyFieldTag => "FIX message field has empty tag",
+      EmptyFieldValue => "FIX message field has empty value",
+      NonNumericFieldTag => "FIX message field tag is not a number",
+      InvalidTagNumber => "FIX message field tag is zero, has leading zeros or is too big",
+      InvalidFieldEncoding => "Invalid encoding of FIX message field, should be UTF-8",
+      InvalidDataLengthFormat(..) => "Data field length value invalid format",
+      DataLengthOverrun(..) => "Declared length of data field overruns the message",
+      DataLengthMismatch(..) => "Declared length of data field does not match its value",
       InvalidFirstField(..) => "Invalid first field, should be `8` but found",
       InvalidSecondField(..) => "Invalid first field, should be `9` but found",
       InvalidThirdField(..) => "Invalid first field, should be `35` but found",
       NotAllRequiredFieldsFound => "Not all required fields found",
       ExtraRequiredFieldFound => "More than 1(repetition) required field found",
+      UnknownBeginString(..) => "Unknown FIX protocol version in `8 - BeginString`",
+      FieldNotDefinedInVersion(..) => "Field is not defined by FIX protocol version of the message",
+      MissingVersionRequiredField(..) => "Field required by FIX protocol version of the message not found",
+      InvalidApplVerID => "Invalid value of `1128 - ApplVerID` or `1137 - DefaultApplVerID`",
+      FieldOutOfOrder(..) => "Tag specified out of required order",
+      MissingRequiredField(..) => "Required field of standard header not found",
+      ValueContainsDelimiter => "FIX message field value contains delimiter",
     }
   }
+
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match *self {
+      InvalidChecksum(ref err) => Some(err),
+      InvalidBodyLengthFormat(ref err) |
+      InvalidDataLengthFormat(ref err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl FIXMessageError {
+  /// Returns `SessionRejectReason` to reject the message with, `None` when
+  /// the message is garbled and should be ignored, e.g. checksum or body
+  /// length is invalid
+  #[allow(deprecated)]
+  pub fn reject_reason(&self) -> Option<SessionRejectReason> {
+    match *self {
+      InvalidChecksum(..) | InvalidChecksumValue | InvalidBodyLength(..) | InvalidBodyLengthFormat(..) |
+      InvalidFieldStructure | MissingFieldSeparator | DataLengthOverrun(..) | InvalidFirstField(..) |
+      InvalidSecondField(..) | UnknownBeginString(..) => None,
+      EmptyFieldTag | NonNumericFieldTag | InvalidTagNumber => Some(SessionRejectReason::InvalidTagNumber),
+      EmptyFieldValue => Some(SessionRejectReason::TagSpecifiedWithoutValue),
+      InvalidFieldEncoding | InvalidDataLengthFormat(..) | DataLengthMismatch(..) | ValueContainsDelimiter =>
+        Some(SessionRejectReason::IncorrectDataFormat),
+      InvalidThirdField(..) | FieldOutOfOrder(..) => Some(SessionRejectReason::TagSpecifiedOutOfRequiredOrder),
+      NotAllRequiredFieldsFound | MissingVersionRequiredField(..) | MissingRequiredField(..) =>
+        Some(SessionRejectReason::RequiredTagMissing),
+      ExtraRequiredFieldFound => Some(SessionRejectReason::TagAppearsMoreThanOnce),
+      FieldNotDefinedInVersion(..) => Some(SessionRejectReason::UndefinedTag),
+      InvalidApplVerID => Some(SessionRejectReason::ValueIsIncorrect),
+    }
+  }
+}
+
+impl From<FIXChecksumValidatorError> for FIXMessageError {
+  fn from(err: FIXChecksumValidatorError) -> FIXMessageError {
+    InvalidChecksum(err)
+  }
+}
+
+/// Location of FIX message field which did not pass validation
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct FIXMessageErrorLocation {
+  /// Index of the field in the message, starting from 0. For generated
+  /// messages it is the index in `FIXMessage.data`
+  pub field_index: usize,
+  /// Tag of the field, empty when the field has no tag
+  pub tag: String,
+  /// Offset of the first byte of the field in the parsed message, `None` for
+  /// generated messages
+  pub byte_offset: Option<usize>,
+}
+
+impl fmt::Display for FIXMessageErrorLocation {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "field {}, tag `{}`", self.field_index, self.tag)?;
+    match self.byte_offset {
+      Some(byte_offset) => write!(f, ", byte offset {}", byte_offset),
+      None => Ok(()),
+    }
+  }
+}
+
+/// This structure describes validation error together with the location of
+/// the field which caused it
+///
+/// Location is `None` when the error concerns the whole message, e.g. when
+/// checksum field not found. It compares equal to `FIXMessageError` by the
+/// error only, so `parse(&message).unwrap_err() == InvalidChecksumValue`
+/// holds regardless of the location.
+#[derive(PartialEq, Debug)]
+pub struct FIXMessageLocatedError {
+  pub error: FIXMessageError,
+  pub location: Option<FIXMessageErrorLocation>,
+}
+
+impl FIXMessageLocatedError {
+  pub fn new(error: FIXMessageError, location: FIXMessageErrorLocation) -> FIXMessageLocatedError {
+    FIXMessageLocatedError { error, location: Some(location) }
+  }
+}
+
+impl From<FIXMessageError> for FIXMessageLocatedError {
+  fn from(error: FIXMessageError) -> FIXMessageLocatedError {
+    FIXMessageLocatedError { error, location: None }
+  }
+}
+
+impl PartialEq<FIXMessageError> for FIXMessageLocatedError {
+  fn eq(&self, other: &FIXMessageError) -> bool {
+    self.error == *other
+  }
+}
+
+impl fmt::Display for FIXMessageLocatedError {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match self.location {
+      Some(ref location) => write!(f, "{} ({})", self.error, location),
+      None => write!(f, "{}", self.error),
+    }
+  }
+}
+
+impl Error for FIXMessageLocatedError {
+  #[allow(deprecated)]
+  fn description(&self) -> &str {
+    self.error.description()
+  }
+
+  // Display already prints the wrapped error, so the chain continues with its source
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    self.error.source()
+  }
+}
+
+/// Errors of FIX message field value conversion, see `FromFixValue`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FIXValueError {
+  FieldNotFound(u32),
+  InvalidEncoding,
+  InvalidInt,
+  InvalidFloat,
+  DecimalOverflow,
+  InvalidChar,
+  InvalidBoolean,
+  InvalidUtcTimestamp,
+  InvalidUtcDateOnly,
+  InvalidLocalMktDate,
+  InvalidMonthYear,
+  InvalidEnumValue,
+}
+
+impl fmt::Display for FIXValueError {
+  #[allow(deprecated)]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      FIXValueError::FieldNotFound(tag) => write!(f, "{}: {}", self.description(), tag),
+      _ => write!(f, "{}", self.description()),
+    }
+  }
+}
+
+impl Error for FIXValueError {
+  fn description(&self) -> &str {
+    match *self {
+      FIXValueError::FieldNotFound(..) => "FIX message field not found",
+      FIXValueError::InvalidEncoding => "Invalid encoding of FIX message field value, should be UTF-8",
+      FIXValueError::InvalidInt => "Invalid int value, should be [-]<digits>",
+      FIXValueError::InvalidFloat => "Invalid float value, should be [-]<digits>[.<digits>]",
+      FIXValueError::DecimalOverflow => "Decimal value has too many digits",
+      FIXValueError::InvalidChar => "Invalid char value, should be a single character",
+      FIXValueError::InvalidBoolean => "Invalid Boolean value, should be `Y` or `N`",
+      FIXValueError::InvalidUtcTimestamp => "Invalid UTCTimestamp value, should be YYYYMMDD-HH:MM:SS[.sss]",
+      FIXValueError::InvalidUtcDateOnly => "Invalid UTCDateOnly value, should be YYYYMMDD",
+      FIXValueError::InvalidLocalMktDate => "Invalid LocalMktDate value, should be YYYYMMDD",
+      FIXValueError::InvalidMonthYear => "Invalid MonthYear value, should be YYYYMM, YYYYMMDD or YYYYMMwN",
+      FIXValueError::InvalidEnumValue => "Value is not one of enumerated values of the field",
+    }
+  }
+}
+
+/// Errors of loading `DataDictionary`
+///
+/// Errors of dictionary definitions carry the line number of the XML element
+/// which caused them.
+#[derive(Debug)]
+pub enum DictionaryError {
+  Io(io::Error),
+  InvalidXml(roxmltree::Error),
+  InvalidRootElement(String),
+  MissingAttribute(String, String, u32),
+  InvalidAttribute(String, String, u32),
+  DuplicateField(String, u32),
+  UnknownField(String, u32),
+  UnknownComponent(String, u32),
+  RecursiveComponent(String, u32),
+}
+
+impl fmt::Display for DictionaryError {
+  #[allow(deprecated)]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      DictionaryError::Io(ref err) => write!(f, "{}: {}", self.description(), err),
+      DictionaryError::InvalidXml(ref err) => write!(f, "{}: {}", self.description(), err),
+      DictionaryError::InvalidRootElement(ref name) => write!(f, "{}: {}", self.description(), name),
+      DictionaryError::MissingAttribute(ref element, ref attribute, line) =>
+        write!(f, "{}: `{}` of `{}` at line {}", self.description(), attribute, element, line),
+      DictionaryError::InvalidAttribute(ref attribute, ref value, line) =>
+        write!(f, "{}: `{}=\"{}\"` at line {}", self.description(), attribute, value, line),
+      DictionaryError::DuplicateField(ref name, line) |
+      DictionaryError::UnknownField(ref name, line) |
+      DictionaryError::UnknownComponent(ref name, line) |
+      DictionaryError::RecursiveComponent(ref name, line) =>
+        write!(f, "{}: `{}` at line {}", self.description(), name, line),
+    }
+  }
+}
+
+impl Error for DictionaryError {
+  fn description(&self) -> &str {
+    match *self {
+      DictionaryError::Io(..) => "Failed to read data dictionary",
+      DictionaryError::InvalidXml(..) => "Data dictionary is not a valid XML",
+      DictionaryError::InvalidRootElement(..) => "Invalid root element of data dictionary, should be `fix` but found",
+      DictionaryError::MissingAttribute(..) => "Data dictionary element has no required attribute",
+      DictionaryError::InvalidAttribute(..) => "Invalid value of data dictionary attribute",
+      DictionaryError::DuplicateField(..) => "Data dictionary field is defined more than once",
+      DictionaryError::UnknownField(..) => "Data dictionary field is not defined",
+      DictionaryError::UnknownComponent(..) => "Data dictionary component is not defined",
+      DictionaryError::RecursiveComponent(..) => "Data dictionary component contains itself",
+    }
+  }
+
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match *self {
+      DictionaryError::Io(ref err) => Some(err),
+      DictionaryError::InvalidXml(ref err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl From<io::Error> for DictionaryError {
+  fn from(err: io::Error) -> DictionaryError {
+    DictionaryError::Io(err)
+  }
+}
+
+impl From<roxmltree::Error> for DictionaryError {
+  fn from(err: roxmltree::Error) -> DictionaryError {
+    DictionaryError::InvalidXml(err)
+  }
+}
+
+/// Reasons of session level rejection, values of `373 - SessionRejectReason`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum SessionRejectReason {
+  InvalidTagNumber,
+  RequiredTagMissing,
+  TagNotDefinedForMessageType,
+  UndefinedTag,
+  TagSpecifiedWithoutValue,
+  ValueIsIncorrect,
+  IncorrectDataFormat,
+  DecryptionProblem,
+  SignatureProblem,
+  CompIdProblem,
+  SendingTimeAccuracyProblem,
+  InvalidMsgType,
+  XmlValidationError,
+  TagAppearsMoreThanOnce,
+  TagSpecifiedOutOfRequiredOrder,
+  RepeatingGroupFieldsOutOfOrder,
+  IncorrectNumInGroupCount,
+  NonDataValueIncludesFieldDelimiter,
+  Other,
+}
+
+impl SessionRejectReason {
+  /// Returns `373 - SessionRejectReason` value
+  pub fn code(&self) -> u32 {
+    match *self {
+      SessionRejectReason::InvalidTagNumber => 0,
+      SessionRejectReason::RequiredTagMissing => 1,
+      SessionRejectReason::TagNotDefinedForMessageType => 2,
+      SessionRejectReason::UndefinedTag => 3,
+      SessionRejectReason::TagSpecifiedWithoutValue => 4,
+      SessionRejectReason::ValueIsIncorrect => 5,
+      SessionRejectReason::IncorrectDataFormat => 6,
+      SessionRejectReason::DecryptionProblem => 7,
+      SessionRejectReason::SignatureProblem => 8,
+      SessionRejectReason::CompIdProblem => 9,
+      SessionRejectReason::SendingTimeAccuracyProblem => 10,
+      SessionRejectReason::InvalidMsgType => 11,
+      SessionRejectReason::XmlValidationError => 12,
+      SessionRejectReason::TagAppearsMoreThanOnce => 13,
+      SessionRejectReason::TagSpecifiedOutOfRequiredOrder => 14,
+      SessionRejectReason::RepeatingGroupFieldsOutOfOrder => 15,
+      SessionRejectReason::IncorrectNumInGroupCount => 16,
+      SessionRejectReason::NonDataValueIncludesFieldDelimiter => 17,
+      SessionRejectReason::Other => 99,
+    }
+  }
+
+  pub fn description(&self) -> &str {
+    match *self {
+      SessionRejectReason::InvalidTagNumber => "Invalid tag number",
+      SessionRejectReason::RequiredTagMissing => "Required tag missing",
+      SessionRejectReason::TagNotDefinedForMessageType => "Tag not defined for this message type",
+      SessionRejectReason::UndefinedTag => "Undefined Tag",
+      SessionRejectReason::TagSpecifiedWithoutValue => "Tag specified without a value",
+      SessionRejectReason::ValueIsIncorrect => "Value is incorrect (out of range) for this tag",
+      SessionRejectReason::IncorrectDataFormat => "Incorrect data format for value",
+      SessionRejectReason::DecryptionProblem => "Decryption problem",
+      SessionRejectReason::SignatureProblem => "Signature problem",
+      SessionRejectReason::CompIdProblem => "CompID problem",
+      SessionRejectReason::SendingTimeAccuracyProblem => "SendingTime accuracy problem",
+      SessionRejectReason::InvalidMsgType => "Invalid MsgType",
+      SessionRejectReason::XmlValidationError => "XML Validation error",
+      SessionRejectReason::TagAppearsMoreThanOnce => "Tag appears more than once",
+      SessionRejectReason::TagSpecifiedOutOfRequiredOrder => "Tag specified out of required order",
+      SessionRejectReason::RepeatingGroupFieldsOutOfOrder => "Repeating group fields out of order",
+      SessionRejectReason::IncorrectNumInGroupCount => "Incorrect NumInGroup count for repeating group",
+      SessionRejectReason::NonDataValueIncludesFieldDelimiter => "Non \"data\" value includes field delimiter",
+      SessionRejectReason::Other => "Other",
+    }
+  }
+}
+
+impl fmt::Display for SessionRejectReason {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{} ({})", self.description(), self.code())
+  }
+}
+
+/// Violation of `DataDictionary` found in FIX message, see
+/// `DataDictionary::validate`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct DictionaryViolation {
+  pub reason: SessionRejectReason,
+  /// Tag of the field which caused the violation, for `RefTagID`
+  pub tag: u32,
+  /// Index of the field in `FIXMessage.data`, `None` when field is missing
+  pub field_index: Option<usize>,
+}
+
+impl fmt::Display for DictionaryViolation {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}: tag `{}`", self.reason, self.tag)?;
+    match self.field_index {
+      Some(field_index) => write!(f, ", field {}", field_index),
+      None => Ok(()),
+    }
+  }
+}
+
+impl Error for DictionaryViolation {
+  fn description(&self) -> &str {
+    self.reason.description()
+  }
+}
+
+/// Errors of parsing repeating groups, see `FIXMessage::group_tree`
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FIXGroupError {
+  /// Value of `NumInGroup` field with the tag is not a number
+  InvalidGroupCount(u32),
+  /// Group with the tag declares one number of entries, but the other found
+  GroupCountMismatch(u32, usize, usize),
+  /// Group with the tag does not start with its delimiter, but with the other
+  /// tag
+  InvalidGroupDelimiter(u32, u32),
+}
+
+impl FIXGroupError {
+  /// Returns `NumInGroup` tag of the group
+  pub fn tag(&self) -> u32 {
+    match *self {
+      FIXGroupError::InvalidGroupCount(tag) |
+      FIXGroupError::GroupCountMismatch(tag, ..) |
+      FIXGroupError::InvalidGroupDelimiter(tag, ..) => tag,
+    }
+  }
+
+  /// Returns `SessionRejectReason` to reject the message with
+  pub fn reject_reason(&self) -> SessionRejectReason {
+    match *self {
+      FIXGroupError::InvalidGroupCount(..) => SessionRejectReason::IncorrectDataFormat,
+      FIXGroupError::GroupCountMismatch(..) => SessionRejectReason::IncorrectNumInGroupCount,
+      FIXGroupError::InvalidGroupDelimiter(..) => SessionRejectReason::RepeatingGroupFieldsOutOfOrder,
+    }
+  }
+}
+
+impl fmt::Display for FIXGroupError {
+  #[allow(deprecated)]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      FIXGroupError::InvalidGroupCount(tag) => write!(f, "{}: {}", self.description(), tag),
+      FIXGroupError::GroupCountMismatch(tag, declared, found) =>
+        write!(f, "{}: {}, declared {}, found {}", self.description(), tag, declared, found),
+      FIXGroupError::InvalidGroupDelimiter(tag, found) =>
+        write!(f, "{}: {}, found {}", self.description(), tag, found),
+    }
+  }
+}
+
+impl Error for FIXGroupError {
+  fn description(&self) -> &str {
+    match *self {
+      FIXGroupError::InvalidGroupCount(..) => "Invalid number of repeating group entries",
+      FIXGroupError::GroupCountMismatch(..) => "Number of repeating group entries does not match declared one",
+      FIXGroupError::InvalidGroupDelimiter(..) => "Repeating group entry does not start with delimiter field",
+    }
+  }
+}
+
+/// Errors of conversion of FIX message into a typed message structure
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum FIXConversionError {
+  /// Message has the other `35 - MsgType`
+  UnexpectedMsgType(String),
+  /// Required field with the tag is missing
+  MissingField(u32),
+  /// Value of field with the tag can not be converted
+  InvalidValue(u32, FIXValueError),
+  InvalidGroup(FIXGroupError),
+}
+
+impl fmt::Display for FIXConversionError {
+  #[allow(deprecated)]
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    match *self {
+      FIXConversionError::UnexpectedMsgType(ref msg_type) => write!(f, "{}: `{}`", self.description(), msg_type),
+      FIXConversionError::MissingField(tag) => write!(f, "{}: {}", self.description(), tag),
+      FIXConversionError::InvalidValue(tag, ref err) => write!(f, "{}: {}, {}", self.description(), tag, err),
+      FIXConversionError::InvalidGroup(ref err) => write!(f, "{}: {}", self.description(), err),
+    }
+  }
+}
+
+impl Error for FIXConversionError {
+  fn description(&self) -> &str {
+    match *self {
+      FIXConversionError::UnexpectedMsgType(..) => "Unexpected message type",
+      FIXConversionError::MissingField(..) => "Required field is missing",
+      FIXConversionError::InvalidValue(..) => "Invalid value of field",
+      FIXConversionError::InvalidGroup(..) => "Invalid repeating group",
+    }
+  }
+
+  fn source(&self) -> Option<&(dyn Error + 'static)> {
+    match *self {
+      FIXConversionError::InvalidValue(_, ref err) => Some(err),
+      FIXConversionError::InvalidGroup(ref err) => Some(err),
+      _ => None,
+    }
+  }
+}
+
+impl From<FIXGroupError> for FIXConversionError {
+  fn from(err: FIXGroupError) -> FIXConversionError {
+    FIXConversionError::InvalidGroup(err)
+  }
 }
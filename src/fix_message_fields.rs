@@ -1,28 +1,176 @@
 //! This module contains a list of all standard FIX message fields
+//!
+//! Tags are numbers, so constants can be compared with `FIXMessageField.tag`
+//! directly.
+//!
+//! Constants and `FIELDS` catalog are generated at build time from
+//! `spec/fields.xml`, a QuickFIX XML specification of FIX 4.0 - 5.0SP2 fields.
+//! Constants are named after fields in screaming snake case, e.g.
+//! `ORIG_SENDING_TIME` for `122 - OrigSendingTime`. The catalog lists every
+//! field of FIX 5.0SP2 and `FIXT.1.1`, user defined tags are not included.
+//!
+//! # Examples
+//!
+//! ```
+//! use fix_message::*;
+//! use fix_message::fix_message_fields::*;
+//!
+//! assert_eq!(DELIVER_TO_COMP_ID, 128);
+//! assert_eq!(tag_name(POSS_DUP_FLAG), Some("PossDupFlag"));
+//! assert_eq!(tag_by_name("ApplVerID"), Some(APPL_VER_ID));
+//! assert_eq!(field_info(SENDING_TIME).unwrap().data_type(), FIXDataType::UtcTimestamp);
+//! ```
+use super::FIXDataType;
 
-// Standard Header fields ======================================================
-/// `8 - BeginString`
-pub const BEGIN_STRING: &'static str = "8";
+include!(concat!(env!("OUT_DIR"), "/fix_message_fields.rs"));
 
-/// `9 - BodyLength`
-pub const BODY_LENGTH: &'static str = "9";
+/// Standard field of the catalog, see `FIELDS`
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct FieldInfo {
+  pub tag: u32,
+  /// Name of the field, e.g. `OrigSendingTime`
+  pub name: &'static str,
+  /// Name of data type as it is in the specification, e.g. `UTCTIMESTAMP`
+  pub type_name: &'static str,
+}
 
-/// `35 - MsgType`
-pub const MSG_TYPE: &'static str = "35";
+impl FieldInfo {
+  /// Returns data type of the field
+  pub fn data_type(&self) -> FIXDataType {
+    FIXDataType::from_name(self.type_name)
+  }
+}
 
-/// `49 - SenderCompID`
-pub const SENDER_COMP_ID: &'static str = "49";
+/// Returns standard field with `tag`
+pub fn field_info(tag: u32) -> Option<&'static FieldInfo> {
+  FIELDS.binary_search_by_key(&tag, |field| field.tag).ok().map(|index| &FIELDS[index])
+}
 
-/// `56 - TargetCompID`
-pub const TARGET_COMP_ID: &'static str = "56";
+/// Returns name of standard field with `tag`
+pub fn tag_name(tag: u32) -> Option<&'static str> {
+  field_info(tag).map(|field| field.name)
+}
 
-/// `34 - MsgSeqNum`
-pub const MSG_SEQ_NUM: &'static str = "34";
+/// Returns tag of standard field by its name, names are case sensitive
+pub fn tag_by_name(name: &str) -> Option<u32> {
+  TAGS_BY_NAME.binary_search_by_key(&name, |&(name, _)| name).ok().map(|index| TAGS_BY_NAME[index].1)
+}
 
-/// `52 - SendingTime`
-pub const SENDING_TIME: &'static str = "52";
+// Length-prefixed data fields =================================================
+/// Pairs of `Length` and `data` fields. Value of `data` field may contain any
+/// bytes, including delimiter, so it is read using the value of `Length` field
+/// which immediately precedes it.
+pub const LENGTH_DATA_FIELDS: &[(u32, u32)] = &[
+  (90, 91),     // SecureDataLen - SecureData
+  (93, 89),     // SignatureLength - Signature
+  (95, 96),     // RawDataLength - RawData
+  (212, 213),   // XmlDataLen - XmlData
+  (348, 349),   // EncodedIssuerLen - EncodedIssuer
+  (350, 351),   // EncodedSecurityDescLen - EncodedSecurityDesc
+  (352, 353),   // EncodedListExecInstLen - EncodedListExecInst
+  (354, 355),   // EncodedTextLen - EncodedText
+  (356, 357),   // EncodedSubjectLen - EncodedSubject
+  (358, 359),   // EncodedHeadlineLen - EncodedHeadline
+  (360, 361),   // EncodedAllocTextLen - EncodedAllocText
+  (362, 363),   // EncodedUnderlyingIssuerLen - EncodedUnderlyingIssuer
+  (364, 365),   // EncodedUnderlyingSecurityDescLen - EncodedUnderlyingSecurityDesc
+  (445, 446),   // EncodedListStatusTextLen - EncodedListStatusText
+  (618, 619),   // EncodedLegIssuerLen - EncodedLegIssuer
+  (621, 622),   // EncodedLegSecurityDescLen - EncodedLegSecurityDesc
+  (1184, 1185), // SecurityXMLLen - SecurityXML
+  (1277, 1278), // DerivativeEncodedIssuerLen - DerivativeEncodedIssuer
+  (1280, 1281), // DerivativeEncodedSecurityDescLen - DerivativeEncodedSecurityDesc
+  (1282, 1283), // DerivativeSecurityXMLLen - DerivativeSecurityXML
+  (1359, 1360), // EncodedSymbolLen - EncodedSymbol
+  (1397, 1398), // EncodedMktSegmDescLen - EncodedMktSegmDesc
+  (1401, 1402), // EncryptedPasswordLen - EncryptedPassword
+  (1403, 1404), // EncryptedNewPasswordLen - EncryptedNewPassword
+  (1468, 1469), // EncodedSecurityListDescLen - EncodedSecurityListDesc
+];
 
+/// Returns tag of `data` field which length is set by `length_tag` field, see
+/// `LENGTH_DATA_FIELDS`
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::fix_message_fields::data_field_tag;
+///
+/// assert_eq!(data_field_tag(95), Some(96));
+/// assert_eq!(data_field_tag(96), None);
+/// ```
+pub fn data_field_tag(length_tag: u32) -> Option<u32> {
+  LENGTH_DATA_FIELDS.iter()
+    .find(|&&(tag, _)| tag == length_tag)
+    .map(|&(_, data_tag)| data_tag)
+}
 
-// Standard Trailer fields =====================================================
-/// `10 - CheckSum`
-pub const CHECK_SUM: &'static str = "10";
+// Standard header and trailer =================================================
+/// Fields of standard header of FIX 4.0 - 4.4 and `FIXT.1.1` in the order
+/// they are listed by the specifications. `NoHops` group entries
+/// `628 - HopCompID`, `629 - HopSendingTime` and `630 - HopRefID` follow
+/// `627 - NoHops`.
+pub const STANDARD_HEADER_TAGS: &[u32] = &[
+  BEGIN_STRING, BODY_LENGTH, MSG_TYPE, APPL_VER_ID, APPL_EXT_ID, CSTM_APPL_VER_ID, SENDER_COMP_ID,
+  TARGET_COMP_ID, ON_BEHALF_OF_COMP_ID, DELIVER_TO_COMP_ID, SECURE_DATA_LEN, SECURE_DATA, MSG_SEQ_NUM,
+  SENDER_SUB_ID, SENDER_LOCATION_ID, TARGET_SUB_ID, TARGET_LOCATION_ID, ON_BEHALF_OF_SUB_ID,
+  ON_BEHALF_OF_LOCATION_ID, DELIVER_TO_SUB_ID, DELIVER_TO_LOCATION_ID, POSS_DUP_FLAG, POSS_RESEND,
+  SENDING_TIME, ORIG_SENDING_TIME, XML_DATA_LEN, XML_DATA, MESSAGE_ENCODING, LAST_MSG_SEQ_NUM_PROCESSED,
+  ON_BEHALF_OF_SENDING_TIME, NO_HOPS, HOP_COMP_ID, HOP_SENDING_TIME, HOP_REF_ID,
+];
+
+/// Fields of standard trailer in the order they are listed by the
+/// specifications
+pub const STANDARD_TRAILER_TAGS: &[u32] = &[SIGNATURE_LENGTH, SIGNATURE, CHECK_SUM];
+
+/// Returns position of standard header field in `STANDARD_HEADER_TAGS`,
+/// fields of `NoHops` group entries share position of `628 - HopCompID`, so
+/// entries keep their order when header fields are sorted by position
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::fix_message_fields::*;
+///
+/// assert!(header_field_position(SENDER_COMP_ID) < header_field_position(MSG_SEQ_NUM));
+/// assert_eq!(header_field_position(HOP_REF_ID), header_field_position(HOP_COMP_ID));
+/// assert_eq!(header_field_position(CL_ORD_ID), None);
+/// ```
+pub fn header_field_position(tag: u32) -> Option<usize> {
+  let tag = match tag {
+    HOP_SENDING_TIME | HOP_REF_ID => HOP_COMP_ID,
+    _ => tag,
+  };
+  STANDARD_HEADER_TAGS.iter().position(|&header_tag| header_tag == tag)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_look_up_catalog_fields() {
+    assert_eq!(tag_name(ORIG_SENDING_TIME), Some("OrigSendingTime"));
+    assert_eq!(tag_name(101), None);
+    assert_eq!(tag_by_name("NoNested2PartyIDs"), Some(NO_NESTED2_PARTY_IDS));
+    assert_eq!(tag_by_name("clordid"), None);
+    assert_eq!(field_info(SIGNATURE).unwrap().data_type(), FIXDataType::Data);
+  }
+
+  #[test]
+  fn it_should_look_up_fix50sp2_fields() {
+    assert_eq!(tag_name(1483), Some("NoComplexEvents"));
+    assert_eq!(tag_by_name("StreamAsgnAckType"), Some(STREAM_ASGN_ACK_TYPE));
+    assert_eq!(STREAM_ASGN_ACK_TYPE, 1503);
+    assert_eq!(field_info(COMPLEX_EVENT_START_TIME).unwrap().data_type(), FIXDataType::UtcTimeOnly);
+    assert_eq!(tag_by_name("SecurityListType"), Some(1470));
+  }
+
+  #[test]
+  fn it_should_pair_length_and_data_fields_of_catalog() {
+    for &(length_tag, data_tag) in LENGTH_DATA_FIELDS {
+      assert_eq!(field_info(length_tag).unwrap().data_type(), FIXDataType::Length);
+      assert!(matches!(field_info(data_tag).unwrap().data_type(), FIXDataType::Data | FIXDataType::XmlData));
+    }
+  }
+}
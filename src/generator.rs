@@ -1,23 +1,500 @@
+use std::str;
+
+use super::{
+  FIXMessageResult, FIXMessage, FIXMessageField, FIXMessageError, FIXMessageErrorLocation,
+  FIXMessageLocatedError, FIXMessageSection, FixVersion, FIX_MESSAGE_DELIMITER_BYTE, FIX_MESSAGE_FIELD_DELIMITER_BYTE
+};
+use super::FIXMessageError::*;
+use super::fix_message_fields::*;
+use super::parser::{is_required_field, required_fields, validate_version_fields};
+use super::checksum::checksum;
+
+fn is_generated_field(tag: u32) -> bool {
+  matches!(tag, BEGIN_STRING | BODY_LENGTH | CHECK_SUM)
+}
+
+// Header fields are generated in the order of standard header, body fields
+// in the order of `FIXMessage.data` and trailer fields last
+fn field_order(tag: u32) -> (FIXMessageSection, usize) {
+  let section = FIXMessageSection::of(tag);
+  let position = match section {
+    FIXMessageSection::Header => header_field_position(tag),
+    FIXMessageSection::Body => None,
+    FIXMessageSection::Trailer => STANDARD_TRAILER_TAGS.iter().position(|&trailer_tag| trailer_tag == tag),
+  };
+  (section, position.unwrap_or(0))
+}
+
+fn located_error(error: FIXMessageError, field_index: usize, field: &FIXMessageField) -> FIXMessageLocatedError {
+  FIXMessageLocatedError::new(error, FIXMessageErrorLocation {
+    field_index,
+    tag: field.tag.to_string(),
+    byte_offset: None,
+  })
+}
+
+fn validate_data_length(length_field: &FIXMessageField, data_field: &FIXMessageField) -> Result<(), FIXMessageError> {
+  let declared_length = String::from_utf8_lossy(&length_field.value)
+    .parse::<usize>().map_err(InvalidDataLengthFormat)?;
+  if declared_length != data_field.value.len() { return Err(DataLengthMismatch(declared_length)) }
+  Ok(())
+}
+
+fn validate_field_structure<'a>(field: &'a FIXMessageField, previous_field: Option<&'a FIXMessageField>) -> Result<&'a FIXMessageField, FIXMessageError> {
+  if field.tag == 0 { return Err(InvalidTagNumber) }
+  if field.value.is_empty() { return Err(EmptyFieldValue) }
+
+  // Value of `data` field may contain delimiter, it is read using preceding `Length` field
+  let length_field = previous_field
+    .filter(|previous_field| data_field_tag(previous_field.tag) == Some(field.tag));
+  match length_field {
+    Some(length_field) => validate_data_length(length_field, field)?,
+    None => if field.value.contains(&FIX_MESSAGE_DELIMITER_BYTE) { return Err(ValueContainsDelimiter) },
+  }
+  Ok(field)
+}
+
+fn push_field(message: &mut Vec<u8>, tag: u32, value: &[u8]) {
+  message.extend_from_slice(tag.to_string().as_bytes());
+  message.push(FIX_MESSAGE_FIELD_DELIMITER_BYTE);
+  message.extend_from_slice(value);
+  message.push(FIX_MESSAGE_DELIMITER_BYTE);
+}
+
+fn validate_and_concatenate(message_fields: Vec<(usize, &FIXMessageField)>) -> FIXMessageResult<Vec<u8>> {
+  let mut required_fields = required_fields();
+  required_fields.retain(|&tag| !is_generated_field(tag));
+
+  let mut body = Vec::new();
+  let mut previous_field = None;
+  for (field_index, field) in message_fields {
+    let field = validate_field_structure(field, previous_field)
+      .map_err(|err| located_error(err, field_index, field))?;
+    previous_field = Some(field);
+
+    if is_required_field(field.tag) {
+      let len_before_retain = required_fields.len();
+      required_fields.retain(|&element| element != field.tag);
+      if required_fields.len() == len_before_retain {
+        return Err(located_error(ExtraRequiredFieldFound, field_index, field))
+      }
+    }
+
+    push_field(&mut body, field.tag, &field.value);
+  }
+
+  if !required_fields.is_empty() { return Err(NotAllRequiredFieldsFound.into()) }
+  Ok(body)
+}
+
 /// This function validates and generates FIX message
 ///
+/// Fields `8 - BeginString`, `9 - BodyLength` and `10 - CheckSum` found in
+/// `FIXMessage.data` are ignored. `BeginString` is taken from
+/// `FIXMessage.version`, `BodyLength` and `CheckSum` are calculated. Every
+/// field of generated message, including `CheckSum`, is terminated by
+/// `FIX_MESSAGE_DELIMITER`.
+///
+/// Standard header fields are generated first in the order of the standard
+/// header, body fields follow in the order of `FIXMessage.data`, trailer
+/// fields `93 - SignatureLength` and `89 - Signature` are generated last, see
+/// `FIXMessageSection`.
+///
+/// # Errors
+/// Returns `InvalidFieldEncoding` when field value is not valid UTF-8, use
+/// `generate_bytes` to generate messages with binary values:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+///     FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+///     FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+///     FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+///     FIXMessageField { tag: 58, value: b"\xff".to_vec() }
+///   ]
+/// };
+///
+/// assert_eq!(generate(&message).unwrap_err(), InvalidFieldEncoding);
+/// # }
+/// ```
+///
+/// Returns `EmptyFieldValue` when field value is empty and `InvalidTagNumber`
+/// when field tag is zero:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"".to_vec() }
+///   ]
+/// };
+///
+/// assert_eq!(generate(&message).unwrap_err(), EmptyFieldValue);
+/// # }
+/// ```
+///
+/// Returns `ValueContainsDelimiter` when value of not `data` field contains
+/// delimiter:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0\x011=2".to_vec() }
+///   ]
+/// };
+///
+/// assert_eq!(generate(&message).unwrap_err(), ValueContainsDelimiter);
+/// # }
+/// ```
+///
+/// Returns `DataLengthMismatch` when value of `Length` field differs from the
+/// length of the following `data` field:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 95, value: b"2".to_vec() } ,
+///     FIXMessageField { tag: 96, value: b"a\x01b".to_vec() }
+///   ]
+/// };
+///
+/// assert_eq!(generate(&message).unwrap_err(), DataLengthMismatch(2));
+/// # }
+/// ```
+///
+/// Returns `NotAllRequiredFieldsFound` when not all fields required for standard
+/// header were provided:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 49, value: b"BRKR".to_vec() }
+///   ]
+/// };
+///
+/// assert_eq!(generate(&message).unwrap_err(), NotAllRequiredFieldsFound);
+/// # }
+/// ```
+///
+/// Returns `ExtraRequiredFieldFound` when required field of standard header
+/// provided more than once:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 35, value: b"0".to_vec() }
+///   ]
+/// };
+///
+/// assert_eq!(generate(&message).unwrap_err(), ExtraRequiredFieldFound);
+/// # }
+/// ```
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+///     FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+///     FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+///     FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+///     FIXMessageField { tag: 112, value: b"19980604-07:58:28".to_vec() }
+///   ]
+/// };
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+/// let expected_message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(generate(&message).unwrap(), expected_message);
+/// # }
+/// ```
+pub fn generate(outbound_message: &FIXMessage) -> FIXMessageResult<String> {
+  generate_bytes(outbound_message)
+    .and_then(|message: Vec<u8>| String::from_utf8(message).map_err(|_| {
+      outbound_message.data.iter().enumerate()
+        .find(|&(_, field)| str::from_utf8(&field.value).is_err())
+        .map_or(InvalidFieldEncoding.into(), |(field_index, field)| located_error(InvalidFieldEncoding, field_index, field))
+    }))
+}
+
+/// This function validates and generates FIX message bytes
+///
+/// Field values are written as is, so they may contain any bytes except
+/// `FIX_MESSAGE_DELIMITER`. It runs the same validations and returns the same
+/// errors as `generate`, except `InvalidFieldEncoding`.
+///
 /// # Examples
-// pub fn generate(outbound_messge: FIXMessage) -> FIXMessageResult<String> {
-pub fn generate() {
-  unimplemented!();
+///
+/// Basic usage:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = FIXMessage {
+///   version: "FIX.4.2".to_string(),
+///   data: vec![
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+///     FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+///     FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+///     FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+///     FIXMessageField { tag: 58, value: b"Caf\xe9 cr\xe8me".to_vec() }
+///   ]
+/// };
+///
+/// let expected_message = b"8=FIX.4.2\x019=65\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+///   52=19980604-07:58:28\x0158=Caf\xe9 cr\xe8me\x0110=228\x01";
+///
+/// assert_eq!(generate_bytes(&message).unwrap(), expected_message.to_vec());
+/// # }
+/// ```
+pub fn generate_bytes(outbound_message: &FIXMessage) -> FIXMessageResult<Vec<u8>> {
+  let version = &outbound_message.version;
+  let fix_version = FixVersion::from_begin_string(version)
+    .ok_or_else(|| UnknownBeginString(version.to_string()))?;
+
+  let mut message_fields = outbound_message.data.iter().enumerate()
+    .filter(|&(_, field)| !is_generated_field(field.tag))
+    .collect::<Vec<(usize, &FIXMessageField)>>();
+  message_fields.sort_by_key(|&(_, field)| field_order(field.tag));
+  let version_fields = message_fields.iter()
+    .map(|&(_, field)| (field.tag, &field.value[..]))
+    .collect::<Vec<(u32, &[u8])>>();
+  let version_result = validate_version_fields(fix_version, &version_fields)
+    .map_err(|(index, error)| match index {
+      Some(index) => located_error(error, message_fields[index].0, message_fields[index].1),
+      None => error.into(),
+    });
+
+  validate_and_concatenate(message_fields)
+    .and_then(|body: Vec<u8>| version_result.map(|_| body))
+    .map(|body: Vec<u8>| {
+      let mut message = Vec::with_capacity(body.len() + 32);
+      push_field(&mut message, BEGIN_STRING, version.as_bytes());
+      push_field(&mut message, BODY_LENGTH, body.len().to_string().as_bytes());
+      message.extend_from_slice(&body);
+
+      let checksum = format!("{:03}", checksum(&message));
+      push_field(&mut message, CHECK_SUM, checksum.as_bytes());
+      message
+    })
 }
 
-// #[cfg(test)]
-// mod tests {
-//   extern crate fix_checksum;
+#[cfg(test)]
+mod tests {
+  use super::super::{FIXMessageField, FIX_MESSAGE_DELIMITER, ParseOptions, parse, parse_bytes,
+    parse_with_options, SessionRejectReason};
+  use super::*;
+  use super::super::test_helpers::{brew_field, brew_heartbeat};
+
+  #[test]
+  fn it_should_generate_fix_message() {
+    let message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+    let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+      "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+
+    assert_eq!(generate(&message).unwrap(), message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string())));
+  }
+
+  #[test]
+  fn it_should_ignore_provided_begin_string_body_length_and_checksum() {
+    let mut message = brew_heartbeat(vec![(112, "19980604-07:58:28"), (10, "000")]);
+    message.data.insert(0, brew_field(9, "1"));
+    message.data.insert(0, brew_field(8, "FIX.4.4"));
+    let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+      "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+
+    assert_eq!(generate(&message).unwrap(), message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string())));
+  }
+
+  #[test]
+  fn it_should_pad_checksum_to_three_digits() {
+    let message = brew_heartbeat(vec![(58, "ab")]);
+    let generated = generate(&message).unwrap();
+
+    assert!(generated.ends_with(&format!("{}10=006{}", FIX_MESSAGE_DELIMITER, FIX_MESSAGE_DELIMITER)));
+  }
+
+  #[test]
+  fn it_should_generate_fix_message_with_binary_values() {
+    let mut message = brew_heartbeat(vec![]);
+    message.data.push(FIXMessageField { tag: 58, value: b"\xc0\x00\xff".to_vec() });
+    let generated = generate_bytes(&message).unwrap();
+
+    assert_eq!(parse_bytes(&generated).unwrap().data[7], message.data[5]);
+    assert_eq!(generate(&message).unwrap_err(), InvalidFieldEncoding);
+  }
+
+  #[test]
+  fn it_should_generate_data_field_containing_delimiter() {
+    let message = brew_heartbeat(vec![(95, "4"), (96, "a\x01=b")]);
+    let generated = generate_bytes(&message).unwrap();
 
-//   use super::{FIX_MESSAGE_DELIMITER, parse, generate, brew_message};
-//   use super::FIXMessageError::*;
-//   use fix_checksum::FIXChecksumValidatorError::*;
+    assert_eq!(&parse_bytes(&generated).unwrap().data[7..9], &message.data[5..7]);
+  }
 
-//   #[test]
-//   fn it_should_generate_fix_message() {
-//     // concatenate message
-//     unimplemented!();
-//   }
-// }
+  #[test]
+  fn it_should_complain_when_data_length_format_is_invalid() {
+    let message = brew_heartbeat(vec![(95, "X"), (96, "a\x01b")]);
 
+    assert_eq!(generate(&message).unwrap_err(), InvalidDataLengthFormat("X".parse::<usize>().unwrap_err()));
+  }
+
+  #[test]
+  fn it_should_complain_when_data_length_does_not_match() {
+    let message = brew_heartbeat(vec![(95, "4"), (96, "a\x01b")]);
+
+    assert_eq!(generate(&message).unwrap_err(), DataLengthMismatch(4));
+  }
+
+  #[test]
+  fn it_should_complain_when_data_field_is_not_preceded_by_its_length() {
+    let message = brew_heartbeat(vec![(95, "3"), (58, "a"), (96, "a\x01b")]);
+
+    assert_eq!(generate(&message).unwrap_err(), ValueContainsDelimiter);
+  }
+
+  #[test]
+  fn it_should_complain_when_version_is_unknown() {
+    let mut message = brew_heartbeat(vec![]);
+    message.version = "".to_string();
+    assert_eq!(generate(&message).unwrap_err(), UnknownBeginString("".to_string()));
+
+    message.version = "FIX.4.2\x019=1".to_string();
+    assert_eq!(generate(&message).unwrap_err(), UnknownBeginString("FIX.4.2\x019=1".to_string()));
+  }
+
+  #[test]
+  fn it_should_validate_fields_by_version() {
+    let mut message = brew_heartbeat(vec![(627, "1"), (628, "HOP")]);
+    message.version = "FIX.4.2".to_string();
+    let err = generate(&message).unwrap_err();
+    assert_eq!(err, FieldNotDefinedInVersion(627));
+    assert_eq!(err.location.unwrap().field_index, 5);
+
+    message.version = "FIX.4.4".to_string();
+    assert!(generate(&message).is_ok());
+
+    let mut logon = brew_heartbeat(vec![(98, "0"), (108, "30")]);
+    logon.version = "FIXT.1.1".to_string();
+    logon.data[0] = brew_field(35, "A");
+    assert_eq!(generate(&logon).unwrap_err(), MissingVersionRequiredField(1137));
+
+    logon.data.push(brew_field(1137, "9"));
+    assert!(generate(&logon).is_ok());
+  }
+
+  #[test]
+  fn it_should_complain_when_value_contains_delimiter() {
+    let message = brew_heartbeat(vec![(58, "a\x01b")]);
+    let err = generate(&message).unwrap_err();
+
+    assert_eq!(err, ValueContainsDelimiter);
+    assert_eq!(err.error.reject_reason(), Some(SessionRejectReason::IncorrectDataFormat));
+  }
+
+  #[test]
+  fn it_should_complain_when_tag_or_value_is_invalid() {
+    assert_eq!(generate(&brew_heartbeat(vec![(0, "a")])).unwrap_err(), InvalidTagNumber);
+    assert_eq!(generate(&brew_heartbeat(vec![(58, "")])).unwrap_err(), EmptyFieldValue);
+  }
+
+  #[test]
+  fn it_should_generate_header_body_and_trailer_in_order() {
+    let mut message = brew_heartbeat(vec![(93, "2"), (89, "SG"), (112, "TEST")]);
+    message.data.swap(0, 1);
+    message.data.insert(0, brew_field(58, "Text"));
+    message.data.push(brew_field(43, "Y"));
+
+    let generated = generate(&message).unwrap();
+    let parsed = parse_with_options(&generated, &ParseOptions::new().with_strict_field_order()).unwrap();
+    let tags = parsed.data.iter().map(|field| field.tag).collect::<Vec<u32>>();
+    assert_eq!(tags, vec![8, 9, 35, 49, 56, 34, 43, 52, 58, 112, 93, 89, 10]);
+  }
+
+  #[test]
+  fn it_should_complain_when_not_all_required_header_fields_presented() {
+    let mut message = brew_heartbeat(vec![]);
+    message.data.remove(3);
+
+    assert_eq!(generate(&message).unwrap_err(), NotAllRequiredFieldsFound);
+  }
+
+  #[test]
+  fn it_should_complain_when_required_header_field_repeated() {
+    let message = brew_heartbeat(vec![(49, "BRKR")]);
+
+    assert_eq!(generate(&message).unwrap_err(), ExtraRequiredFieldFound);
+  }
+
+  #[test]
+  fn it_should_report_location_of_invalid_field() {
+    let mut message = brew_heartbeat(vec![(58, "a\x01b")]);
+    message.data.insert(0, brew_field(8, "FIX.4.2"));
+
+    assert_eq!(generate(&message).unwrap_err().location, Some(FIXMessageErrorLocation {
+      field_index: 6,
+      tag: "58".to_string(),
+      byte_offset: None,
+    }));
+  }
+
+  #[test]
+  fn it_should_generate_message_accepted_by_parser() {
+    let message = brew_heartbeat(vec![(112, "19980604-07:58:28"), (58, "ab")]);
+    let generated = generate(&message).unwrap();
+    let parsed = parse(&generated).unwrap();
+
+    assert_eq!(parsed.version, message.version);
+    assert_eq!(parsed.data[0], brew_field(8, "FIX.4.2"));
+    assert_eq!(parsed.data[1], brew_field(9, "79"));
+    assert_eq!(&parsed.data[2..parsed.data.len() - 1], &message.data[..]);
+    assert_eq!(parsed.data[parsed.data.len() - 1].tag, 10);
+  }
+}
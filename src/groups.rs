@@ -0,0 +1,302 @@
+use std::slice;
+
+use super::{FIXConversionError, FIXGroupError, FIXMessage, FIXMessageField, FIXValueError, FromFixValue};
+
+/// This structure describes repeating group: its `NumInGroup` field, the field
+/// which starts every entry and fields which entries consist of
+///
+/// Specifications are built by hand or derived from data dictionary with
+/// `DataDictionary::group_specs`.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// // NoPartyIDs(453) with nested NoPartySubIDs(802)
+/// let parties = GroupSpec::new(453, 448, vec![448, 447, 452])
+///   .with_group(GroupSpec::new(802, 523, vec![523, 803]));
+///
+/// assert!(parties.is_member(802));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct GroupSpec {
+  /// Tag of `NumInGroup` field which holds the number of entries
+  pub count_tag: u32,
+  /// Tag of the field which starts every entry
+  pub delimiter_tag: u32,
+  /// Tags of fields of entries
+  pub member_tags: Vec<u32>,
+  /// Groups nested in entries
+  pub groups: Vec<GroupSpec>,
+}
+
+impl GroupSpec {
+  pub fn new(count_tag: u32, delimiter_tag: u32, member_tags: Vec<u32>) -> GroupSpec {
+    GroupSpec { count_tag, delimiter_tag, member_tags, groups: vec![] }
+  }
+
+  /// Adds group nested in entries
+  pub fn with_group(mut self, group: GroupSpec) -> GroupSpec {
+    self.groups.push(group);
+    self
+  }
+
+  /// Returns `true` when field or nested group with `tag` belongs to entries
+  pub fn is_member(&self, tag: u32) -> bool {
+    self.member_tags.contains(&tag) || self.groups.iter().any(|group| group.count_tag == tag)
+  }
+}
+
+/// Field or repeating group of FIX message tree, see `FIXMessage::group_tree`
+#[derive(PartialEq, Debug, Clone)]
+pub enum FIXMessageNode<'a> {
+  Field(&'a FIXMessageField),
+  Group(FIXMessageGroup<'a>),
+}
+
+impl <'a> FIXMessageNode<'a> {
+  /// Returns tag of the field or `NumInGroup` tag of the group
+  pub fn tag(&self) -> u32 {
+    match *self {
+      FIXMessageNode::Field(field) => field.tag,
+      FIXMessageNode::Group(ref group) => group.tag(),
+    }
+  }
+}
+
+/// Repeating group of FIX message
+#[derive(PartialEq, Debug, Clone)]
+pub struct FIXMessageGroup<'a> {
+  /// `NumInGroup` field of the group
+  pub count_field: &'a FIXMessageField,
+  pub entries: Vec<FIXGroupEntry<'a>>,
+}
+
+impl <'a> FIXMessageGroup<'a> {
+  /// Returns `NumInGroup` tag of the group
+  pub fn tag(&self) -> u32 {
+    self.count_field.tag
+  }
+
+  pub fn len(&self) -> usize {
+    self.entries.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.entries.is_empty()
+  }
+
+  pub fn iter(&self) -> slice::Iter<'_, FIXGroupEntry<'a>> {
+    self.entries.iter()
+  }
+}
+
+/// Fields and nested groups of repeating group entry, or of the whole message
+/// when it is the root of the tree
+#[derive(PartialEq, Debug, Clone)]
+pub struct FIXGroupEntry<'a> {
+  pub nodes: Vec<FIXMessageNode<'a>>,
+}
+
+impl <'a> FIXGroupEntry<'a> {
+  /// Returns field with `tag`, fields of nested groups are not looked up
+  pub fn get(&self, tag: u32) -> Option<&'a FIXMessageField> {
+    self.fields().find(|field| field.tag == tag)
+  }
+
+  /// Returns value of field with `tag` converted into a typed value, see
+  /// `FIXMessage::get_as`
+  pub fn get_as<T: FromFixValue>(&self, tag: u32) -> Result<T, FIXValueError> {
+    self.get(tag).ok_or(FIXValueError::FieldNotFound(tag))?.value_as()
+  }
+
+  /// Returns value of required field with `tag`, this is what generated
+  /// message structures are built with, see `CodeGenerator`
+  ///
+  /// # Errors
+  ///
+  /// Fails with `MissingField` when field is not found and with `InvalidValue`
+  /// when value can not be converted.
+  pub fn get_required<T: FromFixValue>(&self, tag: u32) -> Result<T, FIXConversionError> {
+    self.get_optional(tag)?.ok_or(FIXConversionError::MissingField(tag))
+  }
+
+  /// Returns value of optional field with `tag`, or `None` when field is not
+  /// found
+  pub fn get_optional<T: FromFixValue>(&self, tag: u32) -> Result<Option<T>, FIXConversionError> {
+    match self.get(tag) {
+      Some(field) => field.value_as().map(Some).map_err(|err| FIXConversionError::InvalidValue(tag, err)),
+      None => Ok(None),
+    }
+  }
+
+  /// Returns group with `NumInGroup` tag
+  pub fn group(&self, tag: u32) -> Option<&FIXMessageGroup<'a>> {
+    self.groups().find(|group| group.tag() == tag)
+  }
+
+  /// Returns fields of the entry in order they are stored
+  pub fn fields(&self) -> impl Iterator<Item = &'a FIXMessageField> + '_ {
+    self.nodes.iter().filter_map(|node| match *node {
+      FIXMessageNode::Field(field) => Some(field),
+      FIXMessageNode::Group(..) => None,
+    })
+  }
+
+  /// Returns groups of the entry in order they are stored
+  pub fn groups(&self) -> impl Iterator<Item = &FIXMessageGroup<'a>> {
+    self.nodes.iter().filter_map(|node| match *node {
+      FIXMessageNode::Group(ref group) => Some(group),
+      FIXMessageNode::Field(..) => None,
+    })
+  }
+}
+
+// Builds tree of flat list of fields
+struct TreeBuilder<'a> {
+  fields: &'a [FIXMessageField],
+  position: usize,
+}
+
+impl <'a> TreeBuilder<'a> {
+  fn peek(&self) -> Option<&'a FIXMessageField> {
+    self.fields.get(self.position)
+  }
+
+  fn next(&mut self) -> Option<&'a FIXMessageField> {
+    let field = self.peek();
+    self.position += 1;
+    field
+  }
+
+  fn root(&mut self, specs: &[GroupSpec]) -> Result<FIXGroupEntry<'a>, FIXGroupError> {
+    let mut nodes = vec![];
+    while let Some(field) = self.peek() {
+      nodes.push(match specs.iter().find(|spec| spec.count_tag == field.tag) {
+        Some(spec) => FIXMessageNode::Group(self.group(spec)?),
+        None => FIXMessageNode::Field(self.next().unwrap()),
+      });
+    }
+    Ok(FIXGroupEntry { nodes })
+  }
+
+  fn group(&mut self, spec: &GroupSpec) -> Result<FIXMessageGroup<'a>, FIXGroupError> {
+    let count_field = self.next().unwrap();
+    let declared = usize::from_fix_value(&count_field.value).map_err(|_| FIXGroupError::InvalidGroupCount(spec.count_tag))?;
+
+    let mut entries = vec![];
+    while self.peek().map(|field| field.tag) == Some(spec.delimiter_tag) {
+      entries.push(self.entry(spec)?);
+    }
+
+    match self.peek() {
+      Some(field) if entries.is_empty() && declared > 0 && spec.is_member(field.tag) =>
+        Err(FIXGroupError::InvalidGroupDelimiter(spec.count_tag, field.tag)),
+      _ if entries.len() != declared => Err(FIXGroupError::GroupCountMismatch(spec.count_tag, declared, entries.len())),
+      _ => Ok(FIXMessageGroup { count_field, entries }),
+    }
+  }
+
+  // Entry starts with the delimiter and lasts until the next delimiter or a
+  // field which is not a member
+  fn entry(&mut self, spec: &GroupSpec) -> Result<FIXGroupEntry<'a>, FIXGroupError> {
+    let mut nodes: Vec<FIXMessageNode<'a>> = vec![];
+    while let Some(field) = self.peek() {
+      if !nodes.is_empty() && field.tag == spec.delimiter_tag { break }
+      nodes.push(match spec.groups.iter().find(|group| group.count_tag == field.tag) {
+        Some(group) => FIXMessageNode::Group(self.group(group)?),
+        None if nodes.is_empty() || spec.member_tags.contains(&field.tag) => FIXMessageNode::Field(self.next().unwrap()),
+        None => break,
+      });
+    }
+    Ok(FIXGroupEntry { nodes })
+  }
+}
+
+impl FIXMessage {
+  /// Returns fields of the message with repeating groups described by `specs`
+  /// assembled into a tree
+  ///
+  /// Fields which do not belong to groups are kept at the root of the tree in
+  /// order they are stored. An entry of a group ends at the next delimiter
+  /// field or at the first field which is not a member of the group.
+  ///
+  /// # Errors
+  ///
+  /// Fails with `FIXGroupError` when `NumInGroup` value is not a number, does
+  /// not match the number of entries found or when the first entry does not
+  /// start with the delimiter field.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  ///
+  /// let message_parts = vec!["8=FIX.4.4", "9=117", "35=D", "49=BRKR", "56=INVMGR", "34=236",
+  ///   "52=19980604-07:59:30", "453=2", "448=BRKR", "452=1", "802=1", "523=DESK", "803=4",
+  ///   "448=INVMGR", "452=3", "55=IBM", "10=077"];
+  /// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+  /// let parties = GroupSpec::new(453, 448, vec![448, 447, 452])
+  ///   .with_group(GroupSpec::new(802, 523, vec![523, 803]));
+  ///
+  /// let tree = fix_message.group_tree(&[parties]).unwrap();
+  /// let parties = tree.group(453).unwrap();
+  ///
+  /// assert_eq!(parties.len(), 2);
+  /// assert_eq!(parties.entries[1].get(448).unwrap().value, b"INVMGR");
+  /// assert_eq!(parties.entries[0].group(802).unwrap().entries[0].get_as::<u32>(803), Ok(4));
+  /// assert_eq!(tree.get(55).unwrap().value, b"IBM");
+  /// ```
+  pub fn group_tree(&self, specs: &[GroupSpec]) -> Result<FIXGroupEntry<'_>, FIXGroupError> {
+    TreeBuilder { fields: &self.data, position: 0 }.root(specs)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::test_helpers::brew_message;
+
+  fn brew_specs() -> Vec<GroupSpec> {
+    vec![GroupSpec::new(268, 269, vec![269, 270, 271])
+      .with_group(GroupSpec::new(453, 448, vec![448, 452]))]
+  }
+
+  #[test]
+  fn it_should_build_tree_of_nested_groups() {
+    let message = brew_message("FIX.4.4", vec![(35, "W"), (268, "2"), (269, "0"), (270, "10.5"), (453, "1"), (448, "A"),
+      (452, "1"), (271, "100"), (269, "1"), (270, "10.6"), (58, "text")]);
+
+    let tree = message.group_tree(&brew_specs()).unwrap();
+    let entries = &tree.group(268).unwrap().entries;
+
+    assert_eq!(tree.nodes.iter().map(FIXMessageNode::tag).collect::<Vec<_>>(), vec![35, 268, 58]);
+    assert_eq!(entries[0].nodes.iter().map(FIXMessageNode::tag).collect::<Vec<_>>(), vec![269, 270, 453, 271]);
+    assert_eq!(entries[0].group(453).unwrap().entries[0].get(448).unwrap().value, b"A");
+    assert_eq!(entries[1].fields().map(|field| field.tag).collect::<Vec<_>>(), vec![269, 270]);
+    assert_eq!(entries[1].groups().count(), 0);
+  }
+
+  #[test]
+  fn it_should_accept_empty_group() {
+    let message = brew_message("FIX.4.4", vec![(35, "W"), (268, "0"), (58, "text")]);
+
+    assert!(message.group_tree(&brew_specs()).unwrap().group(268).unwrap().is_empty());
+  }
+
+  #[test]
+  fn it_should_complain_about_invalid_groups() {
+    let cases = vec![
+      (vec![(268, "x")], FIXGroupError::InvalidGroupCount(268)),
+      (vec![(268, "2"), (269, "0"), (58, "text")], FIXGroupError::GroupCountMismatch(268, 2, 1)),
+      (vec![(268, "1"), (269, "0"), (269, "1")], FIXGroupError::GroupCountMismatch(268, 1, 2)),
+      (vec![(268, "1"), (270, "10.5"), (269, "0")], FIXGroupError::InvalidGroupDelimiter(268, 270)),
+      (vec![(268, "1"), (269, "0"), (453, "2"), (448, "A")], FIXGroupError::GroupCountMismatch(453, 2, 1)),
+    ];
+
+    for (fields, err) in cases {
+      assert_eq!(brew_message("FIX.4.4", fields).group_tree(&brew_specs()), Err(err));
+    }
+  }
+}
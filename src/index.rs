@@ -0,0 +1,261 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Deref;
+
+use super::{FIXMessage, FIXMessageField, FIXValueError, FromFixValue};
+
+fn index_positions(message: &FIXMessage) -> HashMap<u32, Vec<usize>> {
+  let mut positions: HashMap<u32, Vec<usize>> = HashMap::new();
+  for (position, field) in message.data.iter().enumerate() {
+    positions.entry(field.tag).or_default().push(position);
+  }
+  positions
+}
+
+fn tag_positions(positions: &HashMap<u32, Vec<usize>>, tag: u32) -> &[usize] {
+  positions.get(&tag).map_or(&[], |positions| &positions[..])
+}
+
+fn position_after(positions: &[usize], position: usize) -> Option<usize> {
+  let next = match positions.binary_search(&position) {
+    Ok(found) => found + 1,
+    Err(insert_at) => insert_at,
+  };
+  positions.get(next).cloned()
+}
+
+/// This structure indexes fields of FIX message by tag, so that repeated
+/// lookups do not scan the whole message
+///
+/// Index borrows the message, so the message can not be changed while the
+/// index is in use. Use `FIXMessage::index` to build it, or
+/// `FIXIndexedMessage::index` to use index built at parse time.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+/// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+///
+/// let index = fix_message.index();
+///
+/// assert_eq!(index.get(56).unwrap().value, b"INVMGR");
+/// assert_eq!(index.position(56), Some(4));
+/// assert!(!index.contains(58));
+/// ```
+#[derive(Debug, Clone)]
+pub struct FIXMessageIndex<'a> {
+  message: &'a FIXMessage,
+  positions: Cow<'a, HashMap<u32, Vec<usize>>>,
+}
+
+impl <'a> FIXMessageIndex<'a> {
+  /// Creates index of `message` fields
+  pub fn new(message: &'a FIXMessage) -> FIXMessageIndex<'a> {
+    FIXMessageIndex { message, positions: Cow::Owned(index_positions(message)) }
+  }
+
+  fn positions(&self, tag: u32) -> &[usize] {
+    tag_positions(&self.positions, tag)
+  }
+
+  /// Returns position of the first field with `tag`
+  pub fn position(&self, tag: u32) -> Option<usize> {
+    self.positions(tag).first().cloned()
+  }
+
+  /// Returns the first field with `tag`
+  pub fn get(&self, tag: u32) -> Option<&'a FIXMessageField> {
+    self.position(tag).map(|position| &self.message.data[position])
+  }
+
+  /// Returns all fields with `tag` in order they are stored
+  pub fn get_all(&self, tag: u32) -> impl Iterator<Item = &'a FIXMessageField> + '_ {
+    let data = &self.message.data;
+    self.positions(tag).iter().map(move |&position| &data[position])
+  }
+
+  /// Returns position and the first field with `tag` stored after `position`
+  pub fn first_after(&self, tag: u32, position: usize) -> Option<(usize, &'a FIXMessageField)> {
+    position_after(self.positions(tag), position).map(|position| (position, &self.message.data[position]))
+  }
+
+  /// Returns `true` when message has a field with `tag`
+  pub fn contains(&self, tag: u32) -> bool {
+    self.positions.contains_key(&tag)
+  }
+
+  /// Returns value of the first field with `tag` converted into a typed value,
+  /// see `FIXMessage::get_as`
+  pub fn get_as<T: FromFixValue>(&self, tag: u32) -> Result<T, FIXValueError> {
+    self.get(tag).ok_or(FIXValueError::FieldNotFound(tag))?.value_as()
+  }
+}
+
+/// This structure owns FIX message together with index of its fields by tag,
+/// see `parse_indexed`
+///
+/// Message can not be changed while it is indexed, so the index always
+/// matches the fields. Fields are looked up in the index, the rest of
+/// `FIXMessage` methods are available through `Deref`. Use `into_message` to
+/// change the message and `FIXIndexedMessage::new` to index it again.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+/// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+///
+/// let mut message = FIXIndexedMessage::new(fix_message).into_message();
+/// message.data[5].tag = 58;
+/// let indexed_message = FIXIndexedMessage::new(message);
+///
+/// assert_eq!(indexed_message.get(58).unwrap().value, b"235");
+/// assert!(!indexed_message.contains(34));
+/// assert_eq!(indexed_message.sender_comp_id(), Some("BRKR"));
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct FIXIndexedMessage {
+  message: FIXMessage,
+  positions: HashMap<u32, Vec<usize>>,
+}
+
+impl FIXIndexedMessage {
+  /// Creates index of `message` fields and takes ownership of the message
+  pub fn new(message: FIXMessage) -> FIXIndexedMessage {
+    let positions = index_positions(&message);
+    FIXIndexedMessage { message, positions }
+  }
+
+  fn positions(&self, tag: u32) -> &[usize] {
+    tag_positions(&self.positions, tag)
+  }
+
+  /// Returns index of message fields which borrows this message, see
+  /// `FIXMessageIndex`
+  pub fn index(&self) -> FIXMessageIndex<'_> {
+    FIXMessageIndex { message: &self.message, positions: Cow::Borrowed(&self.positions) }
+  }
+
+  /// Returns the first field with `tag`
+  pub fn get(&self, tag: u32) -> Option<&FIXMessageField> {
+    self.positions(tag).first().map(|&position| &self.message.data[position])
+  }
+
+  /// Returns all fields with `tag` in order they are stored
+  pub fn get_all(&self, tag: u32) -> impl Iterator<Item = &FIXMessageField> {
+    let data = &self.message.data;
+    self.positions(tag).iter().map(move |&position| &data[position])
+  }
+
+  /// Returns position and the first field with `tag` stored after `position`
+  pub fn first_after(&self, tag: u32, position: usize) -> Option<(usize, &FIXMessageField)> {
+    position_after(self.positions(tag), position).map(|position| (position, &self.message.data[position]))
+  }
+
+  /// Returns `true` when message has a field with `tag`
+  pub fn contains(&self, tag: u32) -> bool {
+    self.positions.contains_key(&tag)
+  }
+
+  /// Returns value of the first field with `tag` as a string, see
+  /// `FIXMessage::get_str`
+  pub fn get_str(&self, tag: u32) -> Option<&str> {
+    self.get(tag).and_then(|field| field.value_str().ok())
+  }
+
+  /// Returns value of the first field with `tag` converted into a typed value,
+  /// see `FIXMessage::get_as`
+  pub fn get_as<T: FromFixValue>(&self, tag: u32) -> Result<T, FIXValueError> {
+    self.get(tag).ok_or(FIXValueError::FieldNotFound(tag))?.value_as()
+  }
+
+  /// Drops the index and returns the message
+  pub fn into_message(self) -> FIXMessage {
+    self.message
+  }
+}
+
+impl Deref for FIXIndexedMessage {
+  type Target = FIXMessage;
+
+  fn deref(&self) -> &FIXMessage {
+    &self.message
+  }
+}
+
+impl From<FIXMessage> for FIXIndexedMessage {
+  fn from(message: FIXMessage) -> FIXIndexedMessage {
+    FIXIndexedMessage::new(message)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::test_helpers::brew_message;
+
+  #[test]
+  fn it_should_look_up_fields_the_same_way_as_message() {
+    let message = brew_message("FIX.4.4", vec![(35, "V"), (146, "2"), (55, "AAA"), (48, "1"), (55, "BBB"), (48, "2")]);
+    let index = message.index();
+
+    for &tag in &[35, 55, 48, 58] {
+      assert_eq!(index.get(tag), message.get(tag));
+      assert_eq!(index.get_all(tag).collect::<Vec<_>>(), message.get_all(tag).collect::<Vec<_>>());
+      assert_eq!(index.contains(tag), message.contains(tag));
+      for position in 0..7 {
+        assert_eq!(index.first_after(tag, position), message.first_after(tag, position));
+      }
+    }
+  }
+
+  #[test]
+  fn it_should_look_up_fields_of_indexed_message() {
+    let message = brew_message("FIX.4.4", vec![(35, "V"), (146, "2"), (55, "AAA"), (48, "1"), (55, "BBB"), (48, "2")]);
+    let indexed_message = FIXIndexedMessage::new(message.clone());
+
+    for &tag in &[35, 55, 48, 58] {
+      assert_eq!(indexed_message.get(tag), message.get(tag));
+      assert_eq!(indexed_message.get_all(tag).collect::<Vec<_>>(), message.get_all(tag).collect::<Vec<_>>());
+      assert_eq!(indexed_message.contains(tag), message.contains(tag));
+      for position in 0..7 {
+        assert_eq!(indexed_message.first_after(tag, position), message.first_after(tag, position));
+      }
+    }
+    assert_eq!(indexed_message.index().get_all(55).count(), 2);
+    assert_eq!(*indexed_message, message);
+  }
+
+  #[test]
+  fn it_should_look_up_changed_tag_after_indexing_message_again() {
+    let indexed_message = FIXIndexedMessage::new(brew_message("FIX.4.4", vec![(35, "V"), (49, "BRKR"), (56, "INVMGR")]));
+    let mut message = indexed_message.into_message();
+    message.data.swap(1, 2);
+    message.data[0].tag = 58;
+    let indexed_message = FIXIndexedMessage::new(message);
+
+    assert_eq!(indexed_message.get(49).unwrap().value, b"BRKR");
+    assert_eq!(indexed_message.get(56).unwrap().value, b"INVMGR");
+    assert_eq!(indexed_message.get(58).unwrap().value, b"V");
+    assert_eq!(indexed_message.get(35), None);
+    assert_eq!(indexed_message.index().position(49), Some(2));
+  }
+
+  #[test]
+  fn it_should_find_field_after_position() {
+    let message = brew_message("FIX.4.4", vec![(35, "V"), (146, "2"), (55, "AAA"), (48, "1"), (55, "BBB"), (48, "2")]);
+    let index = message.index();
+
+    assert_eq!(index.first_after(55, 2), Some((4, &message.data[4])));
+    assert_eq!(index.first_after(55, 3), Some((4, &message.data[4])));
+    assert_eq!(index.first_after(55, 4), None);
+    assert_eq!(index.first_after(55, usize::MAX), None);
+  }
+}
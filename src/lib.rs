@@ -2,7 +2,9 @@
 //! FIX messages. These functions are agnostic to FIX protocol version.
 //!
 //! The crate provides methods to validate only those message fields which common
-//! for all versions of FIX protocol.
+//! for all versions of FIX protocol. `8 - BeginString` must be one of
+//! `FixVersion` values, header fields are checked against the version, e.g.
+//! `FIXT.1.1` `Logon` requires `1137 - DefaultApplVerID`.
 //! This crate should be used as a middleware for parsing and initial validation
 //! of messages. And as middleware for the final validation, checksum
 //! calculation and FIX message generation.
@@ -22,40 +24,98 @@
 //! let expected_fix_message = FIXMessage {
 //!   version: "FIX.4.2".to_string(),
 //!   data: vec![
-//!     FIXMessageField { tag: "8".to_string(), value: "FIX.4.2".to_string() } ,
-//!     FIXMessageField { tag: "9".to_string(), value: "73".to_string() } ,
-//!     FIXMessageField { tag: "35".to_string(), value: "0".to_string() } ,
-//!     FIXMessageField { tag: "49".to_string(), value: "BRKR".to_string() } ,
-//!     FIXMessageField { tag: "56".to_string(), value: "INVMGR".to_string() } ,
-//!     FIXMessageField { tag: "34".to_string(), value: "235".to_string() } ,
-//!     FIXMessageField { tag: "52".to_string(), value: "19980604-07:58:28".to_string() } ,
-//!     FIXMessageField { tag: "112".to_string(), value: "19980604-07:58:28".to_string() } ,
-//!     FIXMessageField { tag: "10".to_string(), value: "236".to_string() }
+//!     FIXMessageField { tag: 8, value: b"FIX.4.2".to_vec() } ,
+//!     FIXMessageField { tag: 9, value: b"73".to_vec() } ,
+//!     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+//!     FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+//!     FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+//!     FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+//!     FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+//!     FIXMessageField { tag: 112, value: b"19980604-07:58:28".to_vec() } ,
+//!     FIXMessageField { tag: 10, value: b"236".to_vec() }
 //!   ]
 //! };
 //!
 //!  assert_eq!(parse(&message).unwrap(), expected_fix_message);
 //! ```
-//! Please refer to `parse` function documentation for more examples
+//!
+//! ## Generate message
+//! ```
+//! use fix_message::*;
+//!
+//! let fix_message = FIXMessage {
+//!   version: "FIX.4.2".to_string(),
+//!   data: vec![
+//!     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+//!     FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+//!     FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+//!     FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+//!     FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+//!     FIXMessageField { tag: 112, value: b"19980604-07:58:28".to_vec() }
+//!   ]
+//! };
+//!
+//! let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+//!   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+//! let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+//!
+//!  assert_eq!(generate(&fix_message).unwrap(), message);
+//! ```
+//! Please refer to `parse` and `generate` functions documentation for more examples
+//!
+//! # Features
+//! - `tokio` - `FIXCodec` to use with `tokio_util::codec::Framed`
+//! - `chrono` - conversions between `UtcTimestamp` and `chrono::DateTime<Utc>`
+//! - `time` - conversions between `UtcTimestamp` and `time::OffsetDateTime`
 extern crate fix_checksum;
+extern crate roxmltree;
+#[cfg(feature = "tokio")]
+extern crate bytes;
+#[cfg(feature = "chrono")]
+extern crate chrono;
+#[cfg(feature = "time")]
+extern crate time;
+#[cfg(feature = "tokio")]
+extern crate tokio_util;
 
 use std::result;
 
+#[cfg(feature = "tokio")]
+pub use self::codec::*;
+pub use self::builder::*;
+pub use self::codegen::*;
 pub use self::constants::*;
+pub use self::decoder::*;
+pub use self::dictionary::*;
 pub use self::errors::*;
-// pub use self::generator::*;
+pub use self::generator::*;
+pub use self::groups::*;
+pub use self::index::*;
 pub use self::parser::*;
 pub use self::structs::*;
+pub use self::values::*;
 // pub use self::traits::*;
 pub use self::FIXMessageError::*;
 
-pub type FIXMessageResult<'a, T> = result::Result<T, FIXMessageError<'a>>;
+pub type FIXMessageResult<T> = result::Result<T, FIXMessageLocatedError>;
 
+mod builder;
+mod checksum;
+#[cfg(feature = "tokio")]
+mod codec;
+mod codegen;
 mod constants;
+mod decoder;
+mod dictionary;
 mod errors;
 pub mod fix_message_fields;
 
-// mod generator;
+mod generator;
+mod groups;
+mod index;
 mod parser;
 mod structs;
+#[cfg(test)]
+mod test_helpers;
 // mod traits;
+mod values;
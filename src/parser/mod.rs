@@ -1,86 +1,233 @@
+use std::str;
+
+use fix_checksum::FIXChecksumValidatorError::ChecksumFieldNotFound;
+
 use super::{
-  FIXMessageResult, FIXMessage, FIXMessageField, FIXMessageError,
-  FIX_MESSAGE_DELIMITER, FIX_MESSAGE_FIELD_DELIMITER
+  FIXMessageResult, FIXMessage, FIXMessageRef, FIXMessageFieldRef, FIXMessageError,
+  FIXMessageLocatedError, FIXMessageSection, FIXIndexedMessage, FixVersion, ApplVerId, FromFixValue
 };
 use super::FIXMessageError::*;
 use super::fix_message_fields::*;
+use super::checksum::{find_checksum_field, validate as validate_checksum};
+
+use self::scanner::{FieldScanner, RawField};
+
+mod scanner;
+
+/// This function parses tag of FIX message field
+///
+/// Tag should be a positive number without leading zeros, not greater than
+/// `u32::MAX`.
+///
+/// # Errors
+/// Returns `EmptyFieldTag` when tag is empty, `NonNumericFieldTag` when tag
+/// contains anything but digits and `InvalidTagNumber` when tag is zero, has
+/// leading zeros or does not fit `u32`.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// assert_eq!(parse_tag(b"35"), Ok(35));
+/// assert_eq!(parse_tag(b"035"), Err(InvalidTagNumber));
+/// assert_eq!(parse_tag(b"0"), Err(InvalidTagNumber));
+/// assert_eq!(parse_tag(b"4294967296"), Err(InvalidTagNumber));
+/// assert_eq!(parse_tag(b"3A"), Err(NonNumericFieldTag));
+/// ```
+pub fn parse_tag(tag: &[u8]) -> Result<u32, FIXMessageError> {
+  if tag.is_empty() { return Err(EmptyFieldTag) }
+  if !tag.iter().all(u8::is_ascii_digit) { return Err(NonNumericFieldTag) }
+  if tag[0] == b'0' { return Err(InvalidTagNumber) }
+
+  tag.iter()
+    .try_fold(0u32, |number, &digit| number.checked_mul(10)?.checked_add(u32::from(digit - b'0')))
+    .ok_or(InvalidTagNumber)
+}
+
+// Error reported when the field found instead of expected one
+type InvalidFieldOrder = fn(u32) -> FIXMessageError;
 
-use fix_checksum::validate as validate_checksum;
-use fix_checksum::FIXChecksumValidatorError;
+// Parsed field together with the location of its raw bytes
+type LocatedField<'a> = (RawField<'a>, FIXMessageFieldRef<'a>);
 
-fn validate_field_structure<'a>((index, field): (usize, &&'a str)) -> FIXMessageResult<'a, (usize, &'a str, &'a str)> {
-  let field_parts = (*field).splitn(2, FIX_MESSAGE_FIELD_DELIMITER).collect::<Vec<&str>>();
-  let (tag, value) = (field_parts[0], field_parts[1]);
-  if tag == "" || value == "" { return Err(InvalidFieldStructure) }
-  Ok((index, tag, value))
+fn validate_field_structure<'a>(field: FIXMessageResult<RawField<'a>>) -> FIXMessageResult<LocatedField<'a>> {
+  let field = field?;
+  let tag = parse_tag(field.tag).map_err(|err| field.error(err))?;
+  if field.value.is_empty() { return Err(field.error(EmptyFieldValue)) }
+  Ok((field, FIXMessageFieldRef { tag, value: field.value }))
 }
 
-fn validate_fields_order<'a>(res: FIXMessageResult<'a, (usize, &'a str, &'a str)>) -> FIXMessageResult<'a, (&'a str, &'a str)> {
-  res.and_then(|(index, tag, value)| {
-    let tag_to_check: Option<(&str, FIXMessageError)> = match index {
-      0 => Some((BEGIN_STRING, InvalidFirstField(tag))),
-      1 => Some((BODY_LENGTH, InvalidSecondField(tag))),
-      2 => Some((MSG_TYPE, InvalidThirdField(tag))),
+fn validate_fields_order<'a>(res: FIXMessageResult<LocatedField<'a>>) -> FIXMessageResult<LocatedField<'a>> {
+  res.and_then(|(field, parsed_field)| {
+    let tag = parsed_field.tag;
+    let tag_to_check: Option<(u32, InvalidFieldOrder)> = match field.index {
+      0 => Some((BEGIN_STRING, InvalidFirstField)),
+      1 => Some((BODY_LENGTH, InvalidSecondField)),
+      2 => Some((MSG_TYPE, InvalidThirdField)),
       _ => None,
     };
 
     tag_to_check
-      .map_or(Ok((tag, value)), |(expected_tag, err)| {
-        if tag != expected_tag { Err(err) }
-        else { Ok((tag, value)) }
+      .map_or(Ok((field, parsed_field)), |(expected_tag, err)| {
+        if tag != expected_tag { Err(field.error(err(tag))) }
+        else { Ok((field, parsed_field)) }
       })
   })
 }
 
-fn map_to_fix_message_field<'a>(res: FIXMessageResult<'a, (&'a str, &'a str)>) -> FIXMessageResult<'a, FIXMessageField> {
-  res.and_then(|(tag, value)| Ok(FIXMessageField {tag: tag.to_string(), value: value.to_string()}))
-}
-
-fn is_required_field(tag: &str) -> bool {
-  match tag {
+pub(crate) fn is_required_field(tag: u32) -> bool {
+  matches!(tag,
     BEGIN_STRING |
     BODY_LENGTH |
     MSG_TYPE |
     SENDER_COMP_ID |
     TARGET_COMP_ID |
     MSG_SEQ_NUM |
-    SENDING_TIME => true,
-    _ => false,
-  }
+    SENDING_TIME)
 }
 
-fn required_fields<'a>() -> Vec<&'a str> {
+pub(crate) fn required_fields() -> Vec<u32> {
   vec![BEGIN_STRING, BODY_LENGTH, MSG_TYPE, SENDER_COMP_ID, TARGET_COMP_ID, MSG_SEQ_NUM, SENDING_TIME]
 }
 
-fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a, Vec<FIXMessageField>> {
+// Validates fields against header rules of `version`, the error comes with
+// the position of the field in `fields` which caused it, `None` when a
+// required field not found
+pub(crate) fn validate_version_fields(version: FixVersion, fields: &[(u32, &[u8])])
+  -> Result<(), (Option<usize>, FIXMessageError)> {
+  for (index, &(tag, value)) in fields.iter().enumerate() {
+    if !version.defines_field(tag) { return Err((Some(index), FieldNotDefinedInVersion(tag))) }
+    if (tag == APPL_VER_ID || tag == DEFAULT_APPL_VER_ID) && ApplVerId::from_fix_value(value).is_err() {
+      return Err((Some(index), InvalidApplVerID))
+    }
+  }
+
+  let msg_type = fields.iter().find(|&&(tag, _)| tag == MSG_TYPE).map_or(&b""[..], |&(_, value)| value);
+  match version.required_fields(msg_type).iter().find(|&&tag| fields.iter().all(|&(field_tag, _)| field_tag != tag)) {
+    Some(&tag) => Err((None, MissingVersionRequiredField(tag))),
+    None => Ok(()),
+  }
+}
+
+fn validate_and_parse<'a>(message_fields: FieldScanner<'a>) -> FIXMessageResult<Vec<LocatedField<'a>>> {
   let mut required_fields= required_fields();
-  message_fields.iter().enumerate()
+  message_fields
     .map(validate_field_structure)
     .map(validate_fields_order)
-    .map(|res: FIXMessageResult<'a, (&'a str, &'a str)>|
-      res.and_then(|(tag, value)| {
+    .map(|res: FIXMessageResult<LocatedField<'a>>|
+      res.and_then(|(field, parsed_field)| {
+        let tag = parsed_field.tag;
         if is_required_field(tag) {
           let len_before_retain = required_fields.len();
           required_fields.retain(|&element| element != tag);
-          if required_fields.len() != len_before_retain - 1 { return Err(ExtraRequiredFieldFound) }
+          if required_fields.len() == len_before_retain { return Err(field.error(ExtraRequiredFieldFound)) }
         }
 
         if tag == CHECK_SUM {
-          if !required_fields.is_empty() { return Err(NotAllRequiredFieldsFound) }
-          return Ok((tag, value))
+          if !required_fields.is_empty() { return Err(field.error(NotAllRequiredFieldsFound)) }
+          return Ok((field, parsed_field))
         }
 
-        return Ok((tag, value))
+        Ok((field, parsed_field))
       })
     )
-    .map(map_to_fix_message_field)
-    .collect::<FIXMessageResult<Vec<FIXMessageField>>>()
+    .collect::<FIXMessageResult<Vec<LocatedField>>>()
+}
+
+fn field_length(field: &RawField) -> usize {
+  field.tag.len() + field.value.len() + 2
+}
+
+// Checksum pattern found by checksum validation may belong to the value of
+// `data` field, then the message has no trailer
+fn validate_trailer<'a>(message_fields: Vec<LocatedField<'a>>) -> FIXMessageResult<Vec<LocatedField<'a>>> {
+  match message_fields.last() {
+    Some(&(_, field)) if field.tag == CHECK_SUM && message_fields.len() > 2 => Ok(message_fields),
+    _ => Err(InvalidChecksum(ChecksumFieldNotFound).into()),
+  }
+}
+
+fn validate_body_length<'a>(message_fields: Vec<LocatedField<'a>>) -> FIXMessageResult<Vec<LocatedField<'a>>> {
+  let (ref body_length_field, body_length) = message_fields[1];
+  let declared_length = String::from_utf8_lossy(body_length.value)
+    .parse::<usize>().map_err(|err| body_length_field.error(InvalidBodyLengthFormat(err)))?;
+  let computed_length = message_fields.iter()
+    .skip(2)
+    .take_while(|(_, field)| field.tag != CHECK_SUM)
+    .map(|(field, _)| field_length(field))
+    .sum();
+
+  if declared_length != computed_length {
+    return Err(body_length_field.error(InvalidBodyLength(declared_length, computed_length)))
+  }
+  Ok(message_fields)
+}
+
+// Body length of lenient parsing is counted over raw bytes between
+// `9 - BodyLength` and `10 - CheckSum`, so that skipped malformed fields are
+// counted as well
+fn raw_body_length_error(inbound_message: &[u8], message_fields: &[LocatedField]) -> Option<FIXMessageLocatedError> {
+  let (ref body_length_field, body_length) = message_fields[1];
+  let declared_length = match String::from_utf8_lossy(body_length.value).parse::<usize>() {
+    Ok(declared_length) => declared_length,
+    Err(err) => return Some(body_length_field.error(InvalidBodyLengthFormat(err))),
+  };
+  let body_start = body_length_field.offset + field_length(body_length_field);
+  let body_end = find_checksum_field(inbound_message).unwrap_or(inbound_message.len());
+  let computed_length = body_end.saturating_sub(body_start);
+
+  if declared_length != computed_length {
+    return Some(body_length_field.error(InvalidBodyLength(declared_length, computed_length)))
+  }
+  None
+}
+
+// Header fields must precede body fields, trailer fields must follow them
+fn validate_sections<'a>(message_fields: Vec<LocatedField<'a>>) -> FIXMessageResult<Vec<LocatedField<'a>>> {
+  let mut current_section = FIXMessageSection::Header;
+  for (field, parsed_field) in &message_fields {
+    let section = FIXMessageSection::of(parsed_field.tag);
+    if section < current_section { return Err(field.error(FieldOutOfOrder(parsed_field.tag))) }
+    current_section = section;
+  }
+  Ok(message_fields)
+}
+
+// Checksum is validated before the message is split into fields, so the
+// location of checksum field is looked up separately
+fn checksum_error(inbound_message: &[u8], error: FIXMessageError) -> FIXMessageLocatedError {
+  let location = find_checksum_field(inbound_message)
+    .and_then(|offset| FieldScanner::new(inbound_message)
+      .take_while(Result::is_ok)
+      .filter_map(Result::ok)
+      .find(|field| field.offset == offset))
+    .map(|field| field.location());
+  FIXMessageLocatedError { error, location }
 }
 
 /// This function validates and parses FIX message
 ///
 /// # Errors
+/// Errors are returned as `FIXMessageLocatedError` with the index, tag and
+/// byte offset of the field which did not pass validation:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=188"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// let err = parse(&message).unwrap_err();
+/// let location = err.location.as_ref().unwrap();
+///
+/// assert_eq!((location.field_index, location.tag.as_str(), location.byte_offset), (2, "35", Some(15)));
+/// # }
+/// ```
+///
 /// Returns `ChecksumFieldNotFound` when `10 - ChekSum` field not found in message:
 ///
 /// ```
@@ -133,7 +280,40 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 /// # }
 /// ```
 ///
-/// Returns `InvalidFieldStructure` when FIX message field does not have value:
+/// Returns `InvalidBodyLengthFormat` when `9 - BodyLength` field value is not a number:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=7A", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=250"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(),
+///   InvalidBodyLengthFormat("7A".parse::<usize>().unwrap_err()));
+/// # }
+/// ```
+///
+/// Returns `InvalidBodyLength` with declared and computed lengths when
+/// `9 - BodyLength` field value does not match the number of bytes between
+/// `9 - BodyLength` and `10 - CheckSum` fields:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=72", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=235"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), InvalidBodyLength(72, 73));
+/// # }
+/// ```
+///
+/// Returns `EmptyFieldValue` when FIX message field does not have value:
 ///
 /// ```
 /// # #[macro_use] extern crate fix_message;
@@ -144,7 +324,53 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 ///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=188"];
 /// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 ///
-/// assert_eq!(parse(&message).unwrap_err(), InvalidFieldStructure);
+/// assert_eq!(parse(&message).unwrap_err(), EmptyFieldValue);
+/// # }
+/// ```
+///
+/// Returns `EmptyFieldTag` when FIX message field does not have tag:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "=19980604-07:58:28", "10=088"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), EmptyFieldTag);
+/// # }
+/// ```
+///
+/// Returns `NonNumericFieldTag` when FIX message field tag is not a number:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "1A2=19980604-07:58:28", "10=252"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), NonNumericFieldTag);
+/// # }
+/// ```
+///
+/// Returns `MissingFieldSeparator` when FIX message field does not have `=`
+/// separator between tag and value:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112", "10=043"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), MissingFieldSeparator);
 /// # }
 /// ```
 ///
@@ -159,7 +385,7 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 ///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
 /// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 ///
-/// assert_eq!(parse(&message).unwrap_err(), InvalidFirstField("9"));
+/// assert_eq!(parse(&message).unwrap_err(), InvalidFirstField(9));
 /// # }
 /// ```
 ///
@@ -174,7 +400,7 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 ///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
 /// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 ///
-/// assert_eq!(parse(&message).unwrap_err(), InvalidSecondField("35"));
+/// assert_eq!(parse(&message).unwrap_err(), InvalidSecondField(35));
 /// # }
 /// ```
 ///
@@ -189,7 +415,7 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 ///   "35=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
 /// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 ///
-/// assert_eq!(parse(&message).unwrap_err(), InvalidThirdField("34"));
+/// assert_eq!(parse(&message).unwrap_err(), InvalidThirdField(34));
 /// # }
 /// ```
 ///
@@ -225,6 +451,45 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 /// # }
 /// ```
 ///
+/// Returns `UnknownBeginString` when `8 - BeginString` is not one of
+/// `FixVersion` values:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.5", "9=51", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "10=149"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), UnknownBeginString("FIX.4.5".to_string()));
+/// # }
+/// ```
+///
+/// Returns `FieldNotDefinedInVersion` when header field is introduced by a
+/// later version, and `MissingVersionRequiredField` when field required by the
+/// version not found, e.g. `1137 - DefaultApplVerID` of `FIXT.1.1` `Logon`:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=58", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "1128=9", "10=220"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), FieldNotDefinedInVersion(1128));
+///
+/// let message_parts = vec!["8=FIXT.1.1", "9=61", "35=A", "49=BRKR", "56=INVMGR",
+///   "34=1", "52=19980604-07:58:28", "98=0", "108=30", "10=164"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert_eq!(parse(&message).unwrap_err(), MissingVersionRequiredField(1137));
+/// # }
+/// ```
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -241,15 +506,15 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 /// let expected_fix_message = FIXMessage {
 ///   version: "FIX.4.2".to_string(),
 ///   data: vec![
-///     FIXMessageField { tag: "8".to_string(), value: "FIX.4.2".to_string() } ,
-///     FIXMessageField { tag: "9".to_string(), value: "73".to_string() } ,
-///     FIXMessageField { tag: "35".to_string(), value: "0".to_string() } ,
-///     FIXMessageField { tag: "49".to_string(), value: "BRKR".to_string() } ,
-///     FIXMessageField { tag: "56".to_string(), value: "INVMGR".to_string() } ,
-///     FIXMessageField { tag: "34".to_string(), value: "235".to_string() } ,
-///     FIXMessageField { tag: "52".to_string(), value: "19980604-07:58:28".to_string() } ,
-///     FIXMessageField { tag: "112".to_string(), value: "19980604-07:58:28".to_string() } ,
-///     FIXMessageField { tag: "10".to_string(), value: "236".to_string() }
+///     FIXMessageField { tag: 8, value: b"FIX.4.2".to_vec() } ,
+///     FIXMessageField { tag: 9, value: b"73".to_vec() } ,
+///     FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+///     FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+///     FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+///     FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+///     FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+///     FIXMessageField { tag: 112, value: b"19980604-07:58:28".to_vec() } ,
+///     FIXMessageField { tag: 10, value: b"236".to_vec() }
 ///   ]
 /// };
 ///
@@ -257,19 +522,348 @@ fn validate_and_parse<'a>(message_fields: Vec<&'a str>) -> FIXMessageResult<'a,
 /// # }
 /// ```
 pub fn parse(inbound_message: &str) -> FIXMessageResult<FIXMessage> {
+  parse_bytes(inbound_message.as_bytes())
+}
+
+/// This function validates and parses FIX message bytes
+///
+/// FIX is a byte protocol, so message does not have to be valid UTF-8. Only
+/// `8 - BeginString` value is required to be valid UTF-8, other values are
+/// stored as bytes. It runs the same validations and returns the
+/// same errors as `parse`.
+///
+/// Values of `data` fields listed in `fix_message_fields::LENGTH_DATA_FIELDS`
+/// are read using the value of preceding `Length` field, so they may contain
+/// delimiter.
+///
+/// # Errors
+/// Returns `DataLengthOverrun` when declared length of `data` field overruns
+/// the message:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = b"8=FIX.4.2\x019=50\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+///   52=1\x0195=40\x0196=a\x01=b\x0110=252\x01";
+///
+/// assert_eq!(parse_bytes(message).unwrap_err(), DataLengthOverrun(40));
+/// # }
+/// ```
+///
+/// Returns `InvalidFieldEncoding` when `8 - BeginString` value is not valid
+/// UTF-8:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = b"8=FIX.4.\xff\x019=25\x0135=0\x0149=B\x0156=I\x0134=1\x0152=1\x0110=043\x01";
+///
+/// assert_eq!(parse_bytes(message).unwrap_err(), InvalidFieldEncoding);
+/// # }
+/// ```
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message = b"8=FIX.4.2\x019=65\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+///   52=19980604-07:58:28\x0158=Caf\xe9 cr\xe8me\x0110=228\x01";
+///
+/// let fix_message = parse_bytes(message).unwrap();
+///
+/// assert_eq!(fix_message.data[7].value, b"Caf\xe9 cr\xe8me".to_vec());
+/// assert!(fix_message.data[7].value_str().is_err());
+/// assert_eq!(fix_message.data[3].value_str().unwrap(), "BRKR");
+/// # }
+/// ```
+pub fn parse_bytes(inbound_message: &[u8]) -> FIXMessageResult<FIXMessage> {
+  parse_borrowed(inbound_message).map(|fix_message: FIXMessageRef| fix_message.to_owned())
+}
+
+/// This function validates and parses FIX message without copying tags and
+/// values, the parsed message borrows them from `inbound_message`
+///
+/// Accepts both strings and bytes. It runs the same validations and returns
+/// the same errors as `parse_bytes`. Use `FIXMessageRef::to_owned` to convert
+/// parsed message into `FIXMessage`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # #[macro_use] extern crate fix_message;
+/// # fn main() {
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// let fix_message = parse_borrowed(&message).unwrap();
+///
+/// assert_eq!(fix_message.version, "FIX.4.2");
+/// assert_eq!(fix_message.data[3], FIXMessageFieldRef { tag: 49, value: b"BRKR" });
+/// assert_eq!(fix_message.to_owned(), parse(&message).unwrap());
+/// # }
+/// ```
+pub fn parse_borrowed<'a, T>(inbound_message: &'a T) -> FIXMessageResult<FIXMessageRef<'a>>
+  where T: AsRef<[u8]> + ?Sized {
+  parse_borrowed_with_options(inbound_message, &ParseOptions::default())
+}
+
+/// This structure describes optional validations of the parser, all of them
+/// are disabled by default
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// // PossDupFlag(43) of standard header follows Text(58) of the body
+/// let message_parts = vec!["8=FIX.4.2", "9=64", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "58=Text", "43=Y", "10=228"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// assert!(parse(&message).is_ok());
+///
+/// let err = parse_with_options(&message, &ParseOptions::new().with_strict_field_order()).unwrap_err();
+/// assert_eq!(err, FieldOutOfOrder(43));
+/// assert_eq!(err.error.reject_reason(), Some(SessionRejectReason::TagSpecifiedOutOfRequiredOrder));
+/// ```
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct ParseOptions {
+  /// Rejects standard header fields found after body fields and body fields
+  /// found after standard trailer fields with `FieldOutOfOrder`, see
+  /// `FIXMessageSection`
+  pub strict_field_order: bool,
+}
+
+impl ParseOptions {
+  pub fn new() -> ParseOptions {
+    ParseOptions::default()
+  }
+
+  pub fn with_strict_field_order(mut self) -> ParseOptions {
+    self.strict_field_order = true;
+    self
+  }
+}
+
+/// This function validates and parses FIX message with optional validations
+/// of `options`, accepts both strings and bytes
+///
+/// # Errors
+/// Returns the errors of `parse_bytes` and the errors of enabled validations,
+/// see `ParseOptions`.
+pub fn parse_with_options<T>(inbound_message: &T, options: &ParseOptions) -> FIXMessageResult<FIXMessage>
+  where T: AsRef<[u8]> + ?Sized {
+  parse_borrowed_with_options(inbound_message, options).map(|fix_message: FIXMessageRef| fix_message.to_owned())
+}
+
+/// This function validates and parses FIX message with optional validations
+/// of `options` without copying tags and values, see `parse_borrowed` and
+/// `parse_with_options`
+pub fn parse_borrowed_with_options<'a, T>(inbound_message: &'a T, options: &ParseOptions)
+  -> FIXMessageResult<FIXMessageRef<'a>> where T: AsRef<[u8]> + ?Sized {
+  let inbound_message = inbound_message.as_ref();
   validate_checksum(inbound_message)
-    .map_err(|err: FIXChecksumValidatorError| InvalidChecksum(err))
+    .map_err(|err| checksum_error(inbound_message, InvalidChecksum(err)))
     .and_then(|is_valid_value: bool| {
-      if !is_valid_value { return Err(InvalidChecksumValue) }
-      Ok(inbound_message.split(FIX_MESSAGE_DELIMITER).collect::<Vec<&str>>())
+      if !is_valid_value { return Err(checksum_error(inbound_message, InvalidChecksumValue)) }
+      Ok(FieldScanner::new(inbound_message))
     })
     .and_then(validate_and_parse)
-    .and_then(|fix_message_fields: Vec<FIXMessageField>|
-      Ok(FIXMessage {
-        version: fix_message_fields[0].value.clone(),
-        data: fix_message_fields
+    .and_then(validate_trailer)
+    .and_then(validate_body_length)
+    .and_then(|fix_message_fields: Vec<LocatedField<'a>>| {
+      if options.strict_field_order { validate_sections(fix_message_fields) } else { Ok(fix_message_fields) }
+    })
+    .and_then(|fix_message_fields: Vec<LocatedField<'a>>| {
+      let (ref begin_string_field, begin_string) = fix_message_fields[0];
+      let version = str::from_utf8(begin_string.value)
+        .map_err(|_| begin_string_field.error(InvalidFieldEncoding))?;
+      let fix_version = FixVersion::from_begin_string(version)
+        .ok_or_else(|| begin_string_field.error(UnknownBeginString(version.to_string())))?;
+      let fields = fix_message_fields.iter().map(|&(_, field)| (field.tag, field.value)).collect::<Vec<_>>();
+      validate_version_fields(fix_version, &fields).map_err(|(index, error)| match index {
+        Some(index) => fix_message_fields[index].0.error(error),
+        None => error.into(),
+      })?;
+      Ok(FIXMessageRef {
+        version,
+        data: fix_message_fields.into_iter().map(|(_, field)| field).collect()
       })
-    )
+    })
+}
+
+/// This function validates and parses FIX message with optional validations
+/// of `options` and builds index of message fields by tag, so that repeated
+/// lookups do not scan the whole message, see `FIXIndexedMessage`
+///
+/// # Errors
+/// Returns the errors of `parse_with_options`.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+/// let fix_message = parse_indexed(&message, &ParseOptions::new()).unwrap();
+///
+/// assert_eq!(fix_message.get(56).unwrap().value, b"INVMGR");
+/// assert_eq!(fix_message.index().position(56), Some(4));
+/// assert_eq!(fix_message.into_message(), parse(&message).unwrap());
+/// ```
+pub fn parse_indexed<T>(inbound_message: &T, options: &ParseOptions) -> FIXMessageResult<FIXIndexedMessage>
+  where T: AsRef<[u8]> + ?Sized {
+  parse_with_options(inbound_message, options).map(FIXIndexedMessage::new)
+}
+
+/// This structure describes result of lenient parsing, see `parse_lenient`
+#[derive(PartialEq, Debug)]
+pub struct FIXParseReport {
+  /// Message of the fields which could be parsed
+  pub message: FIXMessage,
+  /// Everything that was wrong with the message, ordered by validation step
+  pub warnings: Vec<FIXMessageLocatedError>,
+}
+
+impl FIXParseReport {
+  /// Returns `true` when the message passed all validations
+  pub fn is_valid(&self) -> bool {
+    self.warnings.is_empty()
+  }
+}
+
+// Reports missing and repeated required fields of standard header, each
+// missing field is reported with its tag
+fn required_fields_warnings(message_fields: &[LocatedField]) -> Vec<FIXMessageLocatedError> {
+  let mut warnings = Vec::new();
+  let mut required_fields = required_fields();
+  for (field, parsed_field) in message_fields.iter().filter(|(_, parsed_field)| is_required_field(parsed_field.tag)) {
+    let len_before_retain = required_fields.len();
+    required_fields.retain(|&element| element != parsed_field.tag);
+    if required_fields.len() == len_before_retain { warnings.push(field.error(ExtraRequiredFieldFound)) }
+  }
+  warnings.extend(required_fields.into_iter().map(|tag| MissingRequiredField(tag).into()));
+  warnings
+}
+
+// Returns `FIXMessage.version` of the first `8 - BeginString` field, value
+// which is not valid UTF-8 is converted lossy
+fn lenient_version(message_fields: &[LocatedField], warnings: &mut Vec<FIXMessageLocatedError>) -> String {
+  let begin_string = match message_fields.iter().find(|(_, parsed_field)| parsed_field.tag == BEGIN_STRING) {
+    Some(begin_string) => begin_string,
+    None => return String::new(),
+  };
+  let (ref field, parsed_field) = *begin_string;
+  let version = match str::from_utf8(parsed_field.value) {
+    Ok(version) => version,
+    Err(_) => {
+      warnings.push(field.error(InvalidFieldEncoding));
+      return String::from_utf8_lossy(parsed_field.value).into_owned()
+    },
+  };
+
+  match FixVersion::from_begin_string(version) {
+    Some(fix_version) => {
+      let fields = message_fields.iter().map(|&(_, field)| (field.tag, field.value)).collect::<Vec<_>>();
+      if let Err((index, error)) = validate_version_fields(fix_version, &fields) {
+        warnings.push(match index {
+          Some(index) => message_fields[index].0.error(error),
+          None => error.into(),
+        });
+      }
+    },
+    None => warnings.push(field.error(UnknownBeginString(version.to_string()))),
+  }
+  version.to_string()
+}
+
+/// This function parses FIX message as much as possible, validation errors do
+/// not stop parsing but are collected as warnings
+///
+/// Use it to analyze broken traffic captures and logs, not to accept messages.
+/// Checksum and body length are checked but not required to be valid. Missing
+/// required fields of standard header are reported one by one with
+/// `MissingRequiredField`. Malformed fields are skipped, `data` field which
+/// does not match its declared length is parsed as a regular field.
+/// Validations enabled by `options` are reported as warnings as well.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// // MsgSeqNum(34) is missing, BodyLength(9) and CheckSum(10) are invalid
+/// let message_parts = vec!["8=FIX.4.2", "9=99", "35=0", "49=BRKR", "56=INVMGR",
+///   "52=19980604-07:58:28", "58=Text", "10=000"];
+/// let message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+///
+/// let report = parse_lenient(&message, &ParseOptions::new());
+/// let warnings = report.warnings.iter().map(|warning| &warning.error).collect::<Vec<_>>();
+///
+/// assert!(parse(&message).is_err());
+/// assert_eq!(report.message.get_str(58), Some("Text"));
+/// assert_eq!(warnings, vec![&InvalidChecksumValue, &MissingRequiredField(34), &InvalidBodyLength(99, 52)]);
+/// ```
+pub fn parse_lenient<T>(inbound_message: &T, options: &ParseOptions) -> FIXParseReport
+  where T: AsRef<[u8]> + ?Sized {
+  let inbound_message = inbound_message.as_ref();
+  let mut warnings = Vec::new();
+  let mut message_fields = Vec::new();
+  for field in FieldScanner::lenient(inbound_message) {
+    match validate_field_structure(field) {
+      Ok(field) => message_fields.push(field),
+      Err(err) => warnings.push(err),
+    }
+  }
+
+  let checksum_error = match validate_checksum(inbound_message) {
+    Ok(true) => None,
+    Ok(false) => Some(InvalidChecksumValue),
+    Err(err) => Some(InvalidChecksum(err)),
+  };
+  if let Some(error) = checksum_error {
+    let location = find_checksum_field(inbound_message)
+      .and_then(|offset| message_fields.iter().find(|(field, _)| field.offset == offset))
+      .map(|(field, _)| field.location());
+    warnings.insert(0, FIXMessageLocatedError { error, location });
+  }
+
+  warnings.extend(message_fields.iter().take(3).filter_map(|&field| validate_fields_order(Ok(field)).err()));
+  warnings.extend(required_fields_warnings(&message_fields));
+
+  let has_body_length = message_fields.len() > 1 &&
+    message_fields[0].1.tag == BEGIN_STRING && message_fields[1].1.tag == BODY_LENGTH;
+  if has_body_length {
+    warnings.extend(raw_body_length_error(inbound_message, &message_fields));
+  }
+  if options.strict_field_order {
+    if let Err(err) = validate_sections(message_fields.clone()) { warnings.push(err) }
+  }
+
+  let version = lenient_version(&message_fields, &mut warnings);
+  FIXParseReport {
+    message: FIXMessage {
+      version,
+      data: message_fields.into_iter().map(|(_, field)| field.to_owned()).collect(),
+    },
+    warnings,
+  }
 }
 
 #[cfg(test)]
@@ -0,0 +1,225 @@
+use super::super::{
+  FIXMessageResult, FIXMessageError, FIXMessageErrorLocation, FIXMessageLocatedError,
+  FIX_MESSAGE_DELIMITER_BYTE, FIX_MESSAGE_FIELD_DELIMITER_BYTE
+};
+use super::super::FIXMessageError::*;
+use super::super::fix_message_fields::data_field_tag;
+use super::parse_tag;
+
+fn starts_with_tag(field: &[u8], tag: &[u8]) -> bool {
+  field.starts_with(tag) && field.get(tag.len()) == Some(&FIX_MESSAGE_FIELD_DELIMITER_BYTE)
+}
+
+/// Raw tag and value of FIX message field together with its location
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct RawField<'a> {
+  pub index: usize,
+  pub offset: usize,
+  pub tag: &'a [u8],
+  pub value: &'a [u8],
+}
+
+impl <'a> RawField<'a> {
+  pub fn location(&self) -> FIXMessageErrorLocation {
+    location(self.index, self.tag, self.offset)
+  }
+
+  pub fn error(&self, error: FIXMessageError) -> FIXMessageLocatedError {
+    FIXMessageLocatedError::new(error, self.location())
+  }
+}
+
+fn location(field_index: usize, tag: &[u8], byte_offset: usize) -> FIXMessageErrorLocation {
+  FIXMessageErrorLocation {
+    field_index,
+    tag: String::from_utf8_lossy(tag).into_owned(),
+    byte_offset: Some(byte_offset),
+  }
+}
+
+/// Iterator over tag/value pairs of FIX message
+///
+/// Fields are terminated by delimiter, except `data` fields of
+/// `LENGTH_DATA_FIELDS`: they are read using the value of preceding `Length`
+/// field, so their values may contain delimiter.
+///
+/// Scanning stops at the first error, lenient scanner reports the error and
+/// goes on: malformed field is skipped, `data` field which does not match its
+/// declared length is scanned as a regular field.
+pub struct FieldScanner<'a> {
+  message: &'a [u8],
+  position: usize,
+  index: usize,
+  data_field: Option<(String, usize)>,
+  lenient: bool,
+}
+
+impl <'a> FieldScanner<'a> {
+  pub fn new(message: &'a [u8]) -> FieldScanner<'a> {
+    FieldScanner { message, position: 0, index: 0, data_field: None, lenient: false }
+  }
+
+  pub fn lenient(message: &'a [u8]) -> FieldScanner<'a> {
+    FieldScanner { lenient: true, ..FieldScanner::new(message) }
+  }
+
+  // Lenient scanner goes on from `resume_position`
+  fn fail(&mut self, tag: &[u8], offset: usize, error: FIXMessageError, resume_position: usize)
+    -> Option<FIXMessageResult<RawField<'a>>> {
+    self.position = if self.lenient { resume_position } else { self.message.len() };
+    Some(Err(FIXMessageLocatedError::new(error, location(self.index, tag, offset))))
+  }
+}
+
+impl <'a> Iterator for FieldScanner<'a> {
+  type Item = FIXMessageResult<RawField<'a>>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.position >= self.message.len() { return None }
+
+    let offset = self.position;
+    let rest = &self.message[offset..];
+    let field_length = match self.data_field.take() {
+      Some((ref data_tag, data_length)) if starts_with_tag(rest, data_tag.as_bytes()) => {
+        let field_length = data_length.saturating_add(data_tag.len() + 1);
+        if field_length > rest.len() {
+          return self.fail(data_tag.as_bytes(), offset, DataLengthOverrun(data_length), offset)
+        }
+        if field_length < rest.len() && rest[field_length] != FIX_MESSAGE_DELIMITER_BYTE {
+          return self.fail(data_tag.as_bytes(), offset, DataLengthMismatch(data_length), offset)
+        }
+        field_length
+      },
+      _ => rest.iter().position(|&byte| byte == FIX_MESSAGE_DELIMITER_BYTE).unwrap_or(rest.len()),
+    };
+    self.position += field_length + 1;
+
+    let field = &rest[..field_length];
+    let separator_index = match field.iter().position(|&byte| byte == FIX_MESSAGE_FIELD_DELIMITER_BYTE) {
+      Some(separator_index) => separator_index,
+      None => return self.fail(b"", offset, MissingFieldSeparator, self.position),
+    };
+    let (tag, value) = (&field[..separator_index], &field[separator_index + 1..]);
+
+    if let Some(data_tag) = parse_tag(tag).ok().and_then(data_field_tag) {
+      if !value.is_empty() {
+        match String::from_utf8_lossy(value).parse::<usize>() {
+          Ok(data_length) => self.data_field = Some((data_tag.to_string(), data_length)),
+          Err(err) => return self.fail(tag, offset, InvalidDataLengthFormat(err), self.position),
+        }
+      }
+    }
+
+    let field = RawField { index: self.index, offset, tag, value };
+    self.index += 1;
+    Some(Ok(field))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::super::test_helpers::brew_location;
+
+  fn scan(message: &[u8]) -> Vec<(&[u8], &[u8])> {
+    FieldScanner::new(message)
+      .map(|field| field.map(|field| (field.tag, field.value)))
+      .collect::<FIXMessageResult<Vec<(&[u8], &[u8])>>>().unwrap()
+  }
+
+  #[test]
+  fn it_should_scan_fields_terminated_by_delimiter() {
+    let fields = scan(b"8=FIX.4.2\x0158=a=b\x0110=000\x01");
+
+    assert_eq!(fields, vec![(&b"8"[..], &b"FIX.4.2"[..]), (&b"58"[..], &b"a=b"[..]), (&b"10"[..], &b"000"[..])]);
+  }
+
+  #[test]
+  fn it_should_scan_field_index_and_offset() {
+    let fields = FieldScanner::new(b"8=FIX.4.2\x0158=a=b\x0110=000\x01")
+      .collect::<FIXMessageResult<Vec<RawField>>>().unwrap();
+
+    assert_eq!(fields[2], RawField { index: 2, offset: 17, tag: b"10", value: b"000" });
+  }
+
+  #[test]
+  fn it_should_scan_data_field_using_declared_length() {
+    let fields = scan(b"95=5\x0196=a\x01b=c\x0110=000");
+
+    assert_eq!(fields, vec![(&b"95"[..], &b"5"[..]), (&b"96"[..], &b"a\x01b=c"[..]), (&b"10"[..], &b"000"[..])]);
+  }
+
+  #[test]
+  fn it_should_scan_data_field_not_preceded_by_length_as_regular_field() {
+    let fields = scan(b"95=5\x0158=a\x0196=b\x0110=000");
+
+    assert_eq!(fields[2], (&b"96"[..], &b"b"[..]));
+  }
+
+  #[test]
+  fn it_should_complain_when_data_length_format_is_invalid() {
+    let mut scanner = FieldScanner::new(b"95=X\x0196=a\x0110=000");
+    let err = scanner.next().unwrap().unwrap_err();
+
+    assert_eq!(err, InvalidDataLengthFormat("X".parse::<usize>().unwrap_err()));
+    assert_eq!(err.location, brew_location(0, "95", 0));
+    assert_eq!(scanner.next(), None);
+  }
+
+  #[test]
+  fn it_should_complain_when_data_length_overruns_message() {
+    let mut scanner = FieldScanner::new(b"95=20\x0196=a\x0110=000");
+    scanner.next();
+    let err = scanner.next().unwrap().unwrap_err();
+
+    assert_eq!(err, DataLengthOverrun(20));
+    assert_eq!(err.location, brew_location(1, "96", 6));
+    assert_eq!(scanner.next(), None);
+  }
+
+  #[test]
+  fn it_should_complain_when_data_length_overflows() {
+    let message = format!("95={}\x0196=a\x0110=000", usize::MAX);
+    let mut scanner = FieldScanner::new(message.as_bytes());
+    scanner.next();
+
+    assert_eq!(scanner.next().unwrap().unwrap_err(), DataLengthOverrun(usize::MAX));
+  }
+
+  #[test]
+  fn it_should_complain_when_data_field_is_longer_than_declared() {
+    let mut scanner = FieldScanner::new(b"95=1\x0196=ab\x0110=000");
+    scanner.next();
+
+    assert_eq!(scanner.next().unwrap().unwrap_err(), DataLengthMismatch(1));
+  }
+
+  #[test]
+  fn it_should_go_on_scanning_after_errors_when_lenient() {
+    let results = FieldScanner::lenient(b"8=FIX.4.2\x01garbage\x0195=X\x0158=a\x0195=9\x0196=b\x0110=000")
+      .map(|field| field.map(|field| (field.index, field.tag)).map_err(|err| err.error))
+      .collect::<Vec<_>>();
+
+    assert_eq!(results, vec![
+      Ok((0, &b"8"[..])),
+      Err(MissingFieldSeparator),
+      Err(InvalidDataLengthFormat("X".parse::<usize>().unwrap_err())),
+      Ok((1, &b"58"[..])),
+      Ok((2, &b"95"[..])),
+      Err(DataLengthOverrun(9)),
+      Ok((3, &b"96"[..])),
+      Ok((4, &b"10"[..])),
+    ]);
+  }
+
+  #[test]
+  fn it_should_complain_when_field_has_no_separator() {
+    let mut scanner = FieldScanner::new(b"8=FIX.4.2\x01garbage\x0110=000");
+    scanner.next();
+    let err = scanner.next().unwrap().unwrap_err();
+
+    assert_eq!(err, MissingFieldSeparator);
+    assert_eq!(err.location, brew_location(1, "", 10));
+    assert_eq!(scanner.next(), None);
+  }
+}
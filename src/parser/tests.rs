@@ -1,7 +1,7 @@
-use super::super::{FIXMessage, FIXMessageField, FIX_MESSAGE_DELIMITER};
-use super::super::FIXMessageError::*;
+use super::super::{FIXMessage, FIXMessageField, FIXMessageFieldRef, FIXMessageErrorLocation, FIX_MESSAGE_DELIMITER};
 use super::*;
 use fix_checksum::FIXChecksumValidatorError::*;
+use super::super::test_helpers::brew_location;
 
 #[test]
 fn it_should_complain_when_checksum_not_found() {
@@ -32,12 +32,31 @@ fn it_should_complain_when_checksum_is_invalid() {
 }
 
 #[test]
-fn it_should_complain_when_invalid_field_structure_found() {
+fn it_should_complain_when_body_length_format_is_invalid() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=7A", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=250"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err(),
+    InvalidBodyLengthFormat("7A".parse::<usize>().unwrap_err()));
+}
+
+#[test]
+fn it_should_complain_when_body_length_is_invalid() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=72", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=235"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err(), InvalidBodyLength(72, 73));
+}
+
+#[test]
+fn it_should_complain_when_field_value_is_empty() {
   let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=", "49=BRKR", "56=INVMGR",
     "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=188"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidFieldStructure);
+  assert_eq!(parse(&message).unwrap_err(), EmptyFieldValue);
 }
 
 #[test]
@@ -46,7 +65,7 @@ fn it_should_complain_when_the_first_field_is_incorrect() {
     "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidFirstField("9"));
+  assert_eq!(parse(&message).unwrap_err(), InvalidFirstField(9));
 }
 
 #[test]
@@ -55,7 +74,7 @@ fn it_should_complain_when_the_second_field_is_incorrect() {
     "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidSecondField("35"));
+  assert_eq!(parse(&message).unwrap_err(), InvalidSecondField(35));
 }
 
 #[test]
@@ -64,7 +83,7 @@ fn it_should_complain_when_the_third_field_is_incorrect() {
     "35=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidThirdField("34"));
+  assert_eq!(parse(&message).unwrap_err(), InvalidThirdField(34));
 }
 
 #[test]
@@ -94,17 +113,295 @@ fn it_should_parse_fix_message() {
   let expected_fix_message = FIXMessage {
     version: "FIX.4.2".to_string(),
     data: vec![
-      FIXMessageField { tag: "8".to_string(), value: "FIX.4.2".to_string() } ,
-      FIXMessageField { tag: "9".to_string(), value: "73".to_string() } ,
-      FIXMessageField { tag: "35".to_string(), value: "0".to_string() } ,
-      FIXMessageField { tag: "49".to_string(), value: "BRKR".to_string() } ,
-      FIXMessageField { tag: "56".to_string(), value: "INVMGR".to_string() } ,
-      FIXMessageField { tag: "34".to_string(), value: "235".to_string() } ,
-      FIXMessageField { tag: "52".to_string(), value: "19980604-07:58:28".to_string() } ,
-      FIXMessageField { tag: "112".to_string(), value: "19980604-07:58:28".to_string() } ,
-      FIXMessageField { tag: "10".to_string(), value: "236".to_string() }
+      FIXMessageField { tag: 8, value: b"FIX.4.2".to_vec() } ,
+      FIXMessageField { tag: 9, value: b"73".to_vec() } ,
+      FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+      FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+      FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+      FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+      FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+      FIXMessageField { tag: 112, value: b"19980604-07:58:28".to_vec() } ,
+      FIXMessageField { tag: 10, value: b"236".to_vec() }
     ]
   };
 
   assert_eq!(parse(&message).unwrap(), expected_fix_message);
 }
+
+#[test]
+fn it_should_complain_the_same_way_when_parsing_borrowed_message() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "35=0", "49=BRKR", "56=INVMGR",
+    "52=19980604-07:58:28", "112=19980604-07:58:28", "10=131"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse_borrowed(&message).unwrap_err(), ExtraRequiredFieldFound);
+}
+
+#[test]
+fn it_should_parse_borrowed_fix_message() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  let fix_message = parse_borrowed(&message).unwrap();
+
+  assert_eq!(fix_message.version, "FIX.4.2");
+  assert_eq!(fix_message.data.len(), 9);
+  assert_eq!(fix_message.data[6], FIXMessageFieldRef { tag: 52, value: b"19980604-07:58:28" });
+  assert_eq!(fix_message.data[8], FIXMessageFieldRef { tag: 10, value: b"236" });
+  assert_eq!(fix_message.to_owned(), parse(&message).unwrap());
+}
+
+#[test]
+fn it_should_complain_when_tag_is_not_a_number() {
+  let message: &[u8] = b"8=FIX.4.2\x019=5\x01\xff=0\x0110=056\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), NonNumericFieldTag);
+}
+
+#[test]
+fn it_should_complain_when_tag_has_leading_zeros() {
+  let message: &[u8] = b"8=FIX.4.2\x019=6\x01035=0\x0110=210\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), InvalidTagNumber);
+}
+
+#[test]
+fn it_should_complain_when_tag_is_zero() {
+  let message: &[u8] = b"8=FIX.4.2\x019=25\x0135=0\x0149=B\x0156=I\x010=1\x0152=1\x0110=039\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), InvalidTagNumber);
+}
+
+#[test]
+fn it_should_complain_when_begin_string_is_not_valid_utf8() {
+  let message: &[u8] = b"8=FIX.4.\xff\x019=25\x0135=0\x0149=B\x0156=I\x0134=1\x0152=1\x0110=043\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), InvalidFieldEncoding);
+}
+
+#[test]
+fn it_should_parse_fix_message_with_binary_values() {
+  let message: &[u8] = b"8=FIX.4.2\x019=42\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=1\x0158=\xc0\x00\xff\x0110=169\x01";
+
+  let fix_message = parse_bytes(message).unwrap();
+
+  assert_eq!(fix_message.data[7], FIXMessageField { tag: 58, value: b"\xc0\x00\xff".to_vec() });
+  assert!(fix_message.data[7].value_str().is_err());
+  assert_eq!(fix_message.data[2].value_str().unwrap(), "0");
+  assert_eq!(parse_borrowed(message).unwrap().data[7].value, b"\xc0\x00\xff");
+}
+
+#[test]
+fn it_should_parse_data_field_containing_delimiter() {
+  let message: &[u8] = b"8=FIX.4.2\x019=48\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=1\x0195=4\x0196=a\x01=b\x0110=211\x01";
+
+  let fix_message = parse_bytes(message).unwrap();
+
+  assert_eq!(fix_message.data[8], FIXMessageField { tag: 96, value: b"a\x01=b".to_vec() });
+  assert_eq!(fix_message.data[9].tag, 10);
+}
+
+#[test]
+fn it_should_complain_when_data_length_overruns_message() {
+  let message: &[u8] = b"8=FIX.4.2\x019=50\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=1\x0195=40\x0196=a\x01=b\x0110=252\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), DataLengthOverrun(40));
+}
+
+#[test]
+fn it_should_report_location_of_invalid_field() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=188"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err().location, brew_location(2, "35", 15));
+}
+
+#[test]
+fn it_should_report_location_of_checksum_field() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=231"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err().location, brew_location(8, "10", 88));
+}
+
+#[test]
+fn it_should_report_location_of_body_length_field() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=72", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=235"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err().location, brew_location(1, "9", 10));
+}
+
+#[test]
+fn it_should_not_report_location_of_message_level_errors() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err().location, None);
+}
+
+#[test]
+fn it_should_display_error_location() {
+  let message: &[u8] = b"8=FIX.4.2\x019=5\x01\xff=0\x0110=056\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err().to_string(),
+    "FIX message field tag is not a number (field 2, tag `\u{fffd}`, byte offset 14)");
+}
+
+#[test]
+fn it_should_complain_when_required_header_field_repeated_after_all_found() {
+  let message: &[u8] = b"8=FIX.4.2\x019=30\x0135=0\x0149=B\x0156=I\x0134=1\x0152=1\x0149=C\x0110=072\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), ExtraRequiredFieldFound);
+}
+
+#[test]
+fn it_should_complain_when_field_is_empty() {
+  let message: &[u8] = b"8=FIX.4.2\x019=5\x0135=0\x01\x0110=162";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), MissingFieldSeparator);
+}
+
+#[test]
+fn it_should_complain_when_checksum_belongs_to_data_field() {
+  let message: &[u8] = b"8=FIX.4.2\x019=30\x0135=0\x0149=B\x0156=I\x0134=1\x0152=1\x0195=7\x0196=\x0110=234";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), InvalidChecksum(ChecksumFieldNotFound));
+}
+
+#[test]
+fn it_should_locate_unknown_begin_string() {
+  let message: &[u8] = b"8=FIX.4.5\x019=51\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=19980604-07:58:28\x0110=149\x01";
+
+  let err = parse_bytes(message).unwrap_err();
+  assert_eq!(err, UnknownBeginString("FIX.4.5".to_string()));
+  assert_eq!(err.location, Some(FIXMessageErrorLocation { field_index: 0, tag: "8".to_string(), byte_offset: Some(0) }));
+}
+
+#[test]
+fn it_should_complain_when_header_field_is_not_defined_in_fix_40() {
+  let message: &[u8] = b"8=FIX.4.0\x019=59\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=19980604-07:58:28\x01\
+    369=234\x0110=017\x01";
+
+  let err = parse_bytes(message).unwrap_err();
+  assert_eq!(err, FieldNotDefinedInVersion(369));
+  assert_eq!(err.location.unwrap().field_index, 7);
+}
+
+#[test]
+fn it_should_complain_about_location_id_in_fix_40() {
+  let message: &[u8] = b"8=FIX.4.0\x019=58\x0135=0\x0149=BRKR\x01142=NY\x0156=INVMGR\x0134=235\x01\
+    52=19980604-07:58:28\x0110=019\x01";
+
+  let err = parse_bytes(message).unwrap_err();
+  assert_eq!(err, FieldNotDefinedInVersion(142));
+  assert_eq!(err.location.unwrap().field_index, 4);
+
+  let message: &[u8] = b"8=FIX.4.1\x019=58\x0135=0\x0149=BRKR\x01142=NY\x0156=INVMGR\x0134=235\x01\
+    52=19980604-07:58:28\x0110=020\x01";
+  assert_eq!(parse_bytes(message).unwrap().get_str(142), Some("NY"));
+}
+
+#[test]
+fn it_should_parse_fixt_logon_with_default_appl_ver_id() {
+  let message: &[u8] = b"8=FIXT.1.1\x019=68\x0135=A\x0149=BRKR\x0156=INVMGR\x0134=1\x0152=19980604-07:58:28\x01\
+    98=0\x01108=30\x011137=9\x0110=238\x01";
+
+  assert_eq!(parse_bytes(message).unwrap().version, "FIXT.1.1");
+}
+
+#[test]
+fn it_should_complain_when_appl_ver_id_is_invalid() {
+  let message: &[u8] = b"8=FIXT.1.1\x019=58\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=19980604-07:58:28\x01\
+    1128=Z\x0110=077\x01";
+
+  assert_eq!(parse_bytes(message).unwrap_err(), InvalidApplVerID);
+}
+
+#[test]
+fn it_should_complain_when_body_field_follows_trailer_in_strict_mode() {
+  let message: &[u8] = b"8=FIX.4.2\x019=41\x0135=0\x0149=B\x0156=I\x0134=1\x0152=1\x0193=2\x0189=SG\x0158=T\x0110=128\x01";
+  let options = ParseOptions::new().with_strict_field_order();
+
+  assert!(parse_bytes(message).is_ok());
+  let err = parse_with_options(message, &options).unwrap_err();
+  assert_eq!(err, FieldOutOfOrder(58));
+  assert_eq!(err.location.unwrap().field_index, 9);
+}
+
+#[test]
+fn it_should_parse_valid_message_leniently() {
+  let message: &[u8] = b"8=FIX.4.2\x019=65\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+    52=19980604-07:58:28\x0158=Caf\xe9 cr\xe8me\x0110=228\x01";
+  let report = parse_lenient(message, &ParseOptions::new());
+
+  assert!(report.is_valid());
+  assert_eq!(report.message, parse_bytes(message).unwrap());
+}
+
+#[test]
+fn it_should_collect_warnings_of_malformed_message() {
+  let message: &[u8] = b"9=20\x018=FIX.4.9\x0135=0\x0149=B\x0149=C\x01garbage\x0158=\x0195=9\x0196=a\x01112=T\x0110=1";
+  let report = parse_lenient(message, &ParseOptions::new());
+  let warnings = report.warnings.iter()
+    .map(|warning| (&warning.error, warning.location.as_ref().map(|location| location.field_index)))
+    .collect::<Vec<_>>();
+
+  assert_eq!(warnings, vec![
+    (&InvalidChecksumValue, Some(9)),
+    (&MissingFieldSeparator, Some(5)),
+    (&EmptyFieldValue, Some(5)),
+    (&DataLengthMismatch(9), Some(7)),
+    (&InvalidFirstField(9), Some(0)),
+    (&InvalidSecondField(8), Some(1)),
+    (&ExtraRequiredFieldFound, Some(4)),
+    (&MissingRequiredField(56), None),
+    (&MissingRequiredField(34), None),
+    (&MissingRequiredField(52), None),
+    (&UnknownBeginString("FIX.4.9".to_string()), Some(1)),
+  ]);
+  assert_eq!(report.message.version, "FIX.4.9");
+  let tags = report.message.data.iter().map(|field| field.tag).collect::<Vec<u32>>();
+  assert_eq!(tags, vec![9, 8, 35, 49, 49, 95, 96, 112, 10]);
+}
+
+#[test]
+fn it_should_count_skipped_fields_in_body_length_of_lenient_parsing() {
+  let message: &[u8] = b"8=FIX.4.2\x019=59\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+    52=19980604-07:58:28\x01garbage\x0110=100\x01";
+  let report = parse_lenient(message, &ParseOptions::new());
+  let warnings = report.warnings.iter().map(|warning| &warning.error).collect::<Vec<_>>();
+
+  assert_eq!(warnings, vec![&MissingFieldSeparator]);
+}
+
+#[test]
+fn it_should_look_up_fields_of_borrowed_message() {
+  let message = b"8=FIX.4.2\x019=65\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+    52=19980604-07:58:28\x0158=Caf\xe9 cr\xe8me\x0110=228\x01";
+
+  let fix_message = parse_borrowed(message).unwrap();
+  assert_eq!(fix_message.get(58).unwrap().value, b"Caf\xe9 cr\xe8me");
+  assert_eq!(fix_message.get_str(49), Some("BRKR"));
+  assert_eq!(fix_message.get_as::<u64>(34), Ok(235));
+  assert_eq!(fix_message.first_after(49, 3), None);
+  assert!(!fix_message.contains(112));
+}
+
+#[test]
+fn it_should_build_tag_index_at_parse_time() {
+  let message = b"8=FIX.4.2\x019=65\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x01\
+    52=19980604-07:58:28\x0158=Caf\xe9 cr\xe8me\x0110=228\x01";
+
+  let fix_message = parse_indexed(message, &ParseOptions::new()).unwrap();
+  assert_eq!(fix_message.index().position(58), Some(7));
+  assert_eq!(fix_message.get_str(56), Some("INVMGR"));
+  assert_eq!(fix_message.seq_num(), Some(235));
+  assert_eq!(fix_message.into_message(), parse_bytes(message).unwrap());
+  assert_eq!(parse_indexed(&message[1..], &ParseOptions::new()).unwrap_err(),
+    parse_bytes(&message[1..]).unwrap_err());
+}
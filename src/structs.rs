@@ -1,17 +1,108 @@
 use std::fmt;
+use std::str;
+
+use super::{ApplVerId, FIXMessageIndex, FIXValueError, FixVersion, FromFixValue, MsgType, ToFixValue};
+use super::fix_message_fields::{APPL_VER_ID, MSG_TYPE, SENDER_COMP_ID, TARGET_COMP_ID, MSG_SEQ_NUM,
+  STANDARD_TRAILER_TAGS, header_field_position};
 
 /// This structure represents field/value pair of FIX message
-#[derive(PartialEq, Debug)]
+///
+/// Value is stored as bytes, because FIX fields like `96 - RawData` or
+/// `355 - EncodedText` may legally carry any bytes. Use `value_str` to get
+/// value as a string.
+#[derive(PartialEq, Debug, Clone)]
 pub struct FIXMessageField {
   /// A unique tag number of FIX message filed
-  pub tag: String,
+  pub tag: u32,
   /// A value associated with FIX message field
-  pub value: String
+  pub value: Vec<u8>
+}
+
+impl FIXMessageField {
+  /// Creates field with `value` formatted as FIX value, see `ToFixValue`
+  pub fn from_value<T: ToFixValue + ?Sized>(tag: u32, value: &T) -> FIXMessageField {
+    FIXMessageField { tag, value: value.to_fix_value() }
+  }
+
+  /// Returns field value as a string, fails when value is not valid UTF-8
+  pub fn value_str(&self) -> Result<&str, str::Utf8Error> {
+    str::from_utf8(&self.value)
+  }
+
+  /// Converts field value into a typed value, see `FromFixValue`
+  pub fn value_as<T: FromFixValue>(&self) -> Result<T, FIXValueError> {
+    T::from_fix_value(&self.value)
+  }
 }
 
 impl fmt::Display for FIXMessageField {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-    write!(f, "{}={}", self.tag, self.value)
+    write!(f, "{}={}", self.tag, String::from_utf8_lossy(&self.value))
+  }
+}
+
+/// This structure represents field/value pair of FIX message borrowed from
+/// the inbound message
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct FIXMessageFieldRef<'a> {
+  /// A unique tag number of FIX message filed
+  pub tag: u32,
+  /// A value associated with FIX message field
+  pub value: &'a [u8]
+}
+
+impl <'a> FIXMessageFieldRef<'a> {
+  /// Returns field value as a string, fails when value is not valid UTF-8
+  pub fn value_str(&self) -> Result<&'a str, str::Utf8Error> {
+    str::from_utf8(self.value)
+  }
+
+  /// Converts field value into a typed value, see `FromFixValue`
+  pub fn value_as<T: FromFixValue>(&self) -> Result<T, FIXValueError> {
+    T::from_fix_value(self.value)
+  }
+
+  /// Copies tag and value into `FIXMessageField`
+  pub fn to_owned(&self) -> FIXMessageField {
+    FIXMessageField { tag: self.tag, value: self.value.to_vec() }
+  }
+}
+
+impl <'a> fmt::Display for FIXMessageFieldRef<'a> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}={}", self.tag, String::from_utf8_lossy(self.value))
+  }
+}
+
+/// Section of FIX message: standard header, body or standard trailer
+///
+/// Sections are ordered as they follow each other in the message.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// assert_eq!(FIXMessageSection::of(49), FIXMessageSection::Header);
+/// assert_eq!(FIXMessageSection::of(11), FIXMessageSection::Body);
+/// assert_eq!(FIXMessageSection::of(93), FIXMessageSection::Trailer);
+/// assert!(FIXMessageSection::Header < FIXMessageSection::Body);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum FIXMessageSection {
+  Header,
+  Body,
+  Trailer,
+}
+
+impl FIXMessageSection {
+  /// Returns section of field with `tag`, fields which are not standard
+  /// header or trailer fields belong to body, see `STANDARD_HEADER_TAGS` and
+  /// `STANDARD_TRAILER_TAGS`
+  pub fn of(tag: u32) -> FIXMessageSection {
+    if header_field_position(tag).is_some() { return FIXMessageSection::Header }
+    if STANDARD_TRAILER_TAGS.contains(&tag) { return FIXMessageSection::Trailer }
+    FIXMessageSection::Body
   }
 }
 
@@ -28,7 +119,7 @@ impl fmt::Display for FIXMessageField {
 /// found). Field `8` - `BeginString` will be automatically added to the message
 /// header and have `FIXMessage.version` value. Field `9` - `BodyLength` will be
 /// automatically calculated and added to the message header.
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub struct FIXMessage {
   /// FIX protocol version
   pub version: String,
@@ -36,8 +127,211 @@ pub struct FIXMessage {
   pub data: Vec<FIXMessageField>
 }
 
+impl FIXMessage {
+  /// Returns the first field with `tag`
+  ///
+  /// Fields are looked up sequentially, use `index` or `FIXIndexedMessage` to
+  /// look up fields of large messages many times.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  /// use fix_message::fix_message_fields::SENDER_COMP_ID;
+  ///
+  /// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+  ///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+  /// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+  ///
+  /// assert_eq!(fix_message.get(SENDER_COMP_ID).unwrap().value, b"BRKR");
+  /// assert_eq!(fix_message.get(58), None);
+  /// ```
+  pub fn get(&self, tag: u32) -> Option<&FIXMessageField> {
+    self.data.iter().find(|field| field.tag == tag)
+  }
+
+  /// Returns all fields with `tag` in order they are stored
+  pub fn get_all(&self, tag: u32) -> impl Iterator<Item = &FIXMessageField> {
+    self.data.iter().filter(move |field| field.tag == tag)
+  }
+
+  /// Returns position and the first field with `tag` stored after `position`
+  pub fn first_after(&self, tag: u32, position: usize) -> Option<(usize, &FIXMessageField)> {
+    self.data.iter().enumerate()
+      .skip(position.saturating_add(1))
+      .find(|&(_, field)| field.tag == tag)
+  }
+
+  /// Returns `true` when message has a field with `tag`
+  pub fn contains(&self, tag: u32) -> bool {
+    self.get(tag).is_some()
+  }
+
+  /// Returns value of the first field with `tag` as a string, `None` when
+  /// field not found or value is not valid UTF-8
+  pub fn get_str(&self, tag: u32) -> Option<&str> {
+    self.get(tag).and_then(|field| field.value_str().ok())
+  }
+
+  /// Returns value of the first field with `tag` converted into a typed value
+  ///
+  /// # Errors
+  ///
+  /// Returns `FIXValueError::FieldNotFound` when field not found, or
+  /// conversion error when value does not match the type, see `FromFixValue`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  /// use fix_message::fix_message_fields::{MSG_SEQ_NUM, SENDING_TIME};
+  ///
+  /// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+  ///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+  /// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+  ///
+  /// assert_eq!(fix_message.get_as::<u64>(MSG_SEQ_NUM), Ok(235));
+  /// assert_eq!(fix_message.get_as::<UtcTimestamp>(SENDING_TIME).unwrap().hour(), 7);
+  /// assert_eq!(fix_message.get_as::<u64>(49), Err(FIXValueError::InvalidInt));
+  /// assert_eq!(fix_message.get_as::<u64>(58), Err(FIXValueError::FieldNotFound(58)));
+  /// ```
+  pub fn get_as<T: FromFixValue>(&self, tag: u32) -> Result<T, FIXValueError> {
+    self.get(tag).ok_or(FIXValueError::FieldNotFound(tag))?.value_as()
+  }
+
+  /// Returns `35 - MsgType` of the message, `None` when field not found or
+  /// value is empty or not valid UTF-8
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  ///
+  /// let message_parts = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+  ///   "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+  /// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+  ///
+  /// match fix_message.msg_type() {
+  ///   Some(MsgType::Heartbeat) => {},
+  ///   msg_type => panic!("Unexpected message type {:?}", msg_type),
+  /// }
+  /// ```
+  pub fn msg_type(&self) -> Option<MsgType> {
+    self.get_as(MSG_TYPE).ok()
+  }
+
+  /// Returns FIX protocol version of `FIXMessage.version`, `None` when the
+  /// version is unknown
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use fix_message::*;
+  ///
+  /// let message_parts = vec!["8=FIXT.1.1", "9=58", "35=0", "49=BRKR", "56=INVMGR",
+  ///   "34=235", "52=19980604-07:58:28", "1128=9", "10=044"];
+  /// let fix_message = parse(&message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()))).unwrap();
+  ///
+  /// assert_eq!(fix_message.fix_version(), Some(FixVersion::Fixt11));
+  /// assert_eq!(fix_message.appl_ver_id(), Some(ApplVerId::Fix50Sp2));
+  /// ```
+  pub fn fix_version(&self) -> Option<FixVersion> {
+    FixVersion::from_begin_string(&self.version)
+  }
+
+  /// Returns `1128 - ApplVerID` value, `None` when field not found or value
+  /// is unknown
+  pub fn appl_ver_id(&self) -> Option<ApplVerId> {
+    self.get_as(APPL_VER_ID).ok()
+  }
+
+  /// Returns `49 - SenderCompID` value
+  pub fn sender_comp_id(&self) -> Option<&str> {
+    self.get_str(SENDER_COMP_ID)
+  }
+
+  /// Returns `56 - TargetCompID` value
+  pub fn target_comp_id(&self) -> Option<&str> {
+    self.get_str(TARGET_COMP_ID)
+  }
+
+  /// Returns `34 - MsgSeqNum` value, `None` when field not found or value is
+  /// not a number
+  pub fn seq_num(&self) -> Option<u64> {
+    self.get_str(MSG_SEQ_NUM).and_then(|value| value.parse().ok())
+  }
+
+  /// Builds index of message fields by tag, see `FIXMessageIndex`
+  pub fn index(&self) -> FIXMessageIndex<'_> {
+    FIXMessageIndex::new(self)
+  }
+}
+
 impl fmt::Display for FIXMessage {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(f, "{}\n{:?}", self.version, self.data)
   }
 }
+
+/// This structure represents the whole FIX message borrowed from the inbound
+/// message, see `parse_borrowed`
+///
+/// Field/value pairs stored in order they were in the message. Fields are
+/// looked up the same way as fields of `FIXMessage`.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FIXMessageRef<'a> {
+  /// FIX protocol version
+  pub version: &'a str,
+  /// The list of field/value pairs stored in order they were in message
+  pub data: Vec<FIXMessageFieldRef<'a>>
+}
+
+impl <'a> FIXMessageRef<'a> {
+  /// Returns the first field with `tag`, see `FIXMessage::get`
+  pub fn get(&self, tag: u32) -> Option<&FIXMessageFieldRef<'a>> {
+    self.data.iter().find(|field| field.tag == tag)
+  }
+
+  /// Returns all fields with `tag` in order they are stored
+  pub fn get_all(&self, tag: u32) -> impl Iterator<Item = &FIXMessageFieldRef<'a>> {
+    self.data.iter().filter(move |field| field.tag == tag)
+  }
+
+  /// Returns position and the first field with `tag` stored after `position`
+  pub fn first_after(&self, tag: u32, position: usize) -> Option<(usize, &FIXMessageFieldRef<'a>)> {
+    self.data.iter().enumerate()
+      .skip(position.saturating_add(1))
+      .find(|&(_, field)| field.tag == tag)
+  }
+
+  /// Returns `true` when message has a field with `tag`
+  pub fn contains(&self, tag: u32) -> bool {
+    self.get(tag).is_some()
+  }
+
+  /// Returns value of the first field with `tag` as a string, `None` when
+  /// field not found or value is not valid UTF-8
+  pub fn get_str(&self, tag: u32) -> Option<&'a str> {
+    self.get(tag).and_then(|field| field.value_str().ok())
+  }
+
+  /// Returns value of the first field with `tag` converted into a typed value,
+  /// see `FIXMessage::get_as`
+  pub fn get_as<T: FromFixValue>(&self, tag: u32) -> Result<T, FIXValueError> {
+    self.get(tag).ok_or(FIXValueError::FieldNotFound(tag))?.value_as()
+  }
+
+  /// Copies version and all field/value pairs into `FIXMessage`
+  pub fn to_owned(&self) -> FIXMessage {
+    FIXMessage {
+      version: self.version.to_string(),
+      data: self.data.iter().map(FIXMessageFieldRef::to_owned).collect(),
+    }
+  }
+}
+
+impl <'a> fmt::Display for FIXMessageRef<'a> {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}\n{:?}", self.version, self.data)
+  }
+}
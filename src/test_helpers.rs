@@ -0,0 +1,31 @@
+//! Message builders shared by unit tests
+
+use super::{FIXMessage, FIXMessageErrorLocation, FIXMessageField, FIX_MESSAGE_DELIMITER};
+
+pub fn brew_field(tag: u32, value: &str) -> FIXMessageField {
+  FIXMessageField { tag, value: value.as_bytes().to_vec() }
+}
+
+pub fn brew_message(version: &str, fields: Vec<(u32, &str)>) -> FIXMessage {
+  FIXMessage {
+    version: version.to_string(),
+    data: fields.into_iter().map(|(tag, value)| brew_field(tag, value)).collect()
+  }
+}
+
+/// Builds FIX.4.2 heartbeat with standard header followed by `fields`
+pub fn brew_heartbeat(fields: Vec<(u32, &str)>) -> FIXMessage {
+  let header = vec![(35, "0"), (49, "BRKR"), (56, "INVMGR"), (34, "235"), (52, "19980604-07:58:28")];
+  brew_message("FIX.4.2", header.into_iter().chain(fields).collect())
+}
+
+/// Joins `message_parts` with delimiter, including the one after the last field
+pub fn brew_message_bytes(message_parts: Vec<&str>) -> Vec<u8> {
+  let mut message = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+  message.push(FIX_MESSAGE_DELIMITER);
+  message.into_bytes()
+}
+
+pub fn brew_location(field_index: usize, tag: &str, byte_offset: usize) -> Option<FIXMessageErrorLocation> {
+  Some(FIXMessageErrorLocation { field_index, tag: tag.to_string(), byte_offset: Some(byte_offset) })
+}
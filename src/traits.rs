@@ -4,7 +4,7 @@ pub trait MessageValidator<T> {
   fn validate_fields_order(&self) -> FIXMessageResult<T>;
 }
 
-impl <'a> MessageValidator<(&'a str, &'a str)> for FIXMessageResult<'a, (usize, &'a str, &'a str)> {
+impl <'a> MessageValidator<(&'a str, &'a str)> for FIXMessageResult<(usize, &'a str, &'a str)> {
   fn validate_fields_order(&self) -> FIXMessageResult<(&'a str, &'a str)> {
     unimplemented!();
   }
@@ -26,7 +26,7 @@ impl <'a> MessageValidator<(&'a str, &'a str)> for FIXMessageResult<'a, (usize,
 //   fn map_message_fields(&self) -> FIXMessageResult<T>;
 // }
 
-// impl <'a> MessageMapper<FIXMessageField> for FIXMessageResult<'a, (&'a str, &'a str)> {
+// impl <'a> MessageMapper<FIXMessageField> for FIXMessageResult<(&'a str, &'a str)> {
 //   fn map_message_fields(&self) -> FIXMessageResult<FIXMessageField> {
 //     self.and_then(|(tag, value)| Ok(FIXMessageField {tag: tag.to_string(), value: value.to_string()}))
 //   }
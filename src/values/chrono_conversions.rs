@@ -0,0 +1,69 @@
+use std::convert::TryFrom;
+
+use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+
+use super::super::FIXValueError;
+use super::{UtcDateOnly, UtcTimestamp};
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+
+/// Leap second `60` is represented as second `59` with nanoseconds over one
+/// second, the way `chrono` does
+impl From<UtcTimestamp> for DateTime<Utc> {
+  fn from(timestamp: UtcTimestamp) -> DateTime<Utc> {
+    let (second, nanosecond) = match timestamp.second() {
+      60 => (59, timestamp.nanosecond() + NANOS_PER_SECOND),
+      second => (second, timestamp.nanosecond()),
+    };
+    NaiveDate::from_ymd_opt(i32::from(timestamp.year()), u32::from(timestamp.month()), u32::from(timestamp.day()))
+      .and_then(|date| date.and_hms_nano_opt(u32::from(timestamp.hour()), u32::from(timestamp.minute()),
+        u32::from(second), nanosecond))
+      .expect("UtcTimestamp is a valid chrono date and time")
+      .and_utc()
+  }
+}
+
+/// Fails with `FIXValueError::InvalidUtcTimestamp` when year is out of
+/// `0..=9999` range
+impl TryFrom<DateTime<Utc>> for UtcTimestamp {
+  type Error = FIXValueError;
+
+  fn try_from(date_time: DateTime<Utc>) -> Result<UtcTimestamp, FIXValueError> {
+    let (second, nanosecond) = match date_time.nanosecond() {
+      nanosecond if nanosecond >= NANOS_PER_SECOND => (date_time.second() + 1, nanosecond - NANOS_PER_SECOND),
+      nanosecond => (date_time.second(), nanosecond),
+    };
+    u16::try_from(date_time.year()).ok()
+      .and_then(|year| UtcDateOnly::new(year, date_time.month() as u8, date_time.day() as u8))
+      .and_then(|date| UtcTimestamp::new(date, date_time.hour() as u8, date_time.minute() as u8, second as u8,
+        nanosecond))
+      .ok_or(FIXValueError::InvalidUtcTimestamp)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::FromFixValue;
+
+  #[test]
+  fn it_should_convert_timestamp_to_chrono_and_back() {
+    for value in &["19980604-07:58:28.123456789", "20161231-23:59:60.500"] {
+      let timestamp = UtcTimestamp::from_fix_value(value.as_bytes()).unwrap();
+      let date_time = DateTime::<Utc>::from(timestamp);
+
+      assert_eq!(UtcTimestamp::try_from(date_time), Ok(timestamp));
+    }
+
+    let date_time = DateTime::<Utc>::from(UtcTimestamp::from_fix_value(b"19980604-07:58:28.125").unwrap());
+
+    assert_eq!(date_time.to_rfc3339(), "1998-06-04T07:58:28.125+00:00");
+  }
+
+  #[test]
+  fn it_should_complain_when_chrono_year_is_out_of_range() {
+    let date_time = NaiveDate::from_ymd_opt(10000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap().and_utc();
+
+    assert_eq!(UtcTimestamp::try_from(date_time), Err(FIXValueError::InvalidUtcTimestamp));
+  }
+}
@@ -0,0 +1,561 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::super::FIXValueError;
+use super::{FromFixValue, is_digits};
+
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+const NANOS_PER_MILLI: u32 = 1_000_000;
+const NANOS_PER_MICRO: u32 = 1_000;
+
+fn is_leap_year(year: u16) -> bool {
+  year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+  match month {
+    2 if is_leap_year(year) => 29,
+    2 => 28,
+    4 | 6 | 9 | 11 => 30,
+    _ => 31,
+  }
+}
+
+fn is_valid_date(year: u16, month: u8, day: u8) -> bool {
+  (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month)
+}
+
+// Parses fixed number of digits
+fn parse_number(value: &[u8]) -> Option<u32> {
+  if !is_digits(value) { return None }
+  Some(value.iter().fold(0, |number, &digit| number * 10 + u32::from(digit - b'0')))
+}
+
+// Parses `YYYYMMDD`
+fn parse_date(value: &[u8]) -> Option<(u16, u8, u8)> {
+  if value.len() != 8 { return None }
+  let year = parse_number(&value[..4])? as u16;
+  let month = parse_number(&value[4..6])? as u8;
+  let day = parse_number(&value[6..])? as u8;
+  if !is_valid_date(year, month, day) { return None }
+  Some((year, month, day))
+}
+
+macro_rules! date_type {
+  ($(#[$attr:meta])* $name:ident, $error:ident) => {
+    $(#[$attr])*
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct $name {
+      year: u16,
+      month: u8,
+      day: u8,
+    }
+
+    impl $name {
+      /// Creates date, returns `None` when date does not exist
+      pub fn new(year: u16, month: u8, day: u8) -> Option<$name> {
+        if year > 9999 || !is_valid_date(year, month, day) { return None }
+        Some($name { year, month, day })
+      }
+
+      pub fn year(&self) -> u16 {
+        self.year
+      }
+
+      pub fn month(&self) -> u8 {
+        self.month
+      }
+
+      pub fn day(&self) -> u8 {
+        self.day
+      }
+    }
+
+    impl FromFixValue for $name {
+      fn from_fix_value(value: &[u8]) -> Result<$name, FIXValueError> {
+        parse_date(value)
+          .map(|(year, month, day)| $name { year, month, day })
+          .ok_or(FIXValueError::$error)
+      }
+    }
+
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+      }
+    }
+  }
+}
+
+date_type!(
+  /// FIX `UTCDateOnly` value, date in `YYYYMMDD` format
+  UtcDateOnly, InvalidUtcDateOnly);
+
+date_type!(
+  /// FIX `LocalMktDate` value, date local to the market center in `YYYYMMDD`
+  /// format
+  LocalMktDate, InvalidLocalMktDate);
+
+/// Precision of fractional seconds of `UtcTimestamp`
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum TimestampPrecision {
+  /// `HH:MM:SS`
+  Seconds,
+  /// `HH:MM:SS.sss`
+  Milliseconds,
+  /// `HH:MM:SS.ssssss`
+  Microseconds,
+  /// `HH:MM:SS.sssssssss`
+  Nanoseconds,
+}
+
+impl TimestampPrecision {
+  // Number of fractional digits
+  fn digits(self) -> usize {
+    match self {
+      TimestampPrecision::Seconds => 0,
+      TimestampPrecision::Milliseconds => 3,
+      TimestampPrecision::Microseconds => 6,
+      TimestampPrecision::Nanoseconds => 9,
+    }
+  }
+
+  // Number of nanoseconds in the smallest unit
+  fn unit(self) -> u32 {
+    10u32.pow(9 - self.digits() as u32)
+  }
+
+  fn from_digits(digits: usize) -> Option<TimestampPrecision> {
+    match digits {
+      0 => Some(TimestampPrecision::Seconds),
+      3 => Some(TimestampPrecision::Milliseconds),
+      6 => Some(TimestampPrecision::Microseconds),
+      9 => Some(TimestampPrecision::Nanoseconds),
+      _ => None,
+    }
+  }
+}
+
+/// FIX `UTCTimestamp` value, date and time in `YYYYMMDD-HH:MM:SS[.sss]`,
+/// `YYYYMMDD-HH:MM:SS.ssssss` or `YYYYMMDD-HH:MM:SS.sssssssss` format
+///
+/// Precision of parsed value is preserved when it is formatted back.
+/// Timestamps are equal and ordered by the moment of time they represent
+/// regardless of precision. Leap second `60` is ordered as the first second of
+/// the next minute the same way `unix_timestamp_nanos` counts it, and precedes
+/// the timestamp of the next minute with the same nanoseconds.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let timestamp = UtcTimestamp::from_fix_value(b"19980604-07:58:28.125").unwrap();
+///
+/// assert_eq!((timestamp.year(), timestamp.month(), timestamp.day()), (1998, 6, 4));
+/// assert_eq!((timestamp.hour(), timestamp.minute(), timestamp.second()), (7, 58, 28));
+/// assert_eq!(timestamp.millisecond(), 125);
+/// assert_eq!(timestamp.precision(), TimestampPrecision::Milliseconds);
+/// assert_eq!(timestamp.to_string(), "19980604-07:58:28.125");
+///
+/// let orig_sending_time = UtcTimestamp::from_fix_value(b"19980604-07:58:27.999850").unwrap();
+///
+/// assert_eq!(timestamp.duration_since(&orig_sending_time),
+///   Some(std::time::Duration::from_micros(125_150)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct UtcTimestamp {
+  date: UtcDateOnly,
+  hour: u8,
+  minute: u8,
+  second: u8,
+  nanosecond: u32,
+  precision: TimestampPrecision,
+}
+
+impl UtcTimestamp {
+  /// Creates timestamp, returns `None` when date does not exist or time is out
+  /// of range. Second `60` is accepted for leap seconds.
+  ///
+  /// Precision is the lowest one which represents `nanosecond` exactly, use
+  /// `with_precision` to change it.
+  pub fn new(date: UtcDateOnly, hour: u8, minute: u8, second: u8, nanosecond: u32) -> Option<UtcTimestamp> {
+    if hour > 23 || minute > 59 || second > 60 || nanosecond >= NANOS_PER_SECOND { return None }
+    let precision = [TimestampPrecision::Seconds, TimestampPrecision::Milliseconds, TimestampPrecision::Microseconds]
+      .iter()
+      .cloned()
+      .find(|precision| nanosecond % precision.unit() == 0)
+      .unwrap_or(TimestampPrecision::Nanoseconds);
+    Some(UtcTimestamp { date, hour, minute, second, nanosecond, precision })
+  }
+
+  /// Returns timestamp with `precision`, fractional seconds which can not be
+  /// represented with `precision` are truncated
+  pub fn with_precision(&self, precision: TimestampPrecision) -> UtcTimestamp {
+    let nanosecond = self.nanosecond - self.nanosecond % precision.unit();
+    UtcTimestamp { nanosecond, precision, ..*self }
+  }
+
+  pub fn date(&self) -> UtcDateOnly {
+    self.date
+  }
+
+  pub fn year(&self) -> u16 {
+    self.date.year()
+  }
+
+  pub fn month(&self) -> u8 {
+    self.date.month()
+  }
+
+  pub fn day(&self) -> u8 {
+    self.date.day()
+  }
+
+  pub fn hour(&self) -> u8 {
+    self.hour
+  }
+
+  pub fn minute(&self) -> u8 {
+    self.minute
+  }
+
+  pub fn second(&self) -> u8 {
+    self.second
+  }
+
+  pub fn millisecond(&self) -> u32 {
+    self.nanosecond / NANOS_PER_MILLI
+  }
+
+  pub fn microsecond(&self) -> u32 {
+    self.nanosecond / NANOS_PER_MICRO
+  }
+
+  pub fn nanosecond(&self) -> u32 {
+    self.nanosecond
+  }
+
+  pub fn precision(&self) -> TimestampPrecision {
+    self.precision
+  }
+
+  /// Returns nanoseconds since `1970-01-01 00:00:00` UTC, leap second `60` is
+  /// counted as the first second of the next minute
+  pub fn unix_timestamp_nanos(&self) -> i128 {
+    let seconds = days_from_civil(self.year(), self.month(), self.day()) * 86_400 +
+      i64::from(self.hour) * 3_600 + i64::from(self.minute) * 60 + i64::from(self.second);
+    i128::from(seconds) * i128::from(NANOS_PER_SECOND) + i128::from(self.nanosecond)
+  }
+
+  /// Creates timestamp from nanoseconds since `1970-01-01 00:00:00` UTC,
+  /// returns `None` when the year is out of `0 - 9999` range
+  pub fn from_unix_timestamp_nanos(nanos: i128) -> Option<UtcTimestamp> {
+    let nanos_per_day = 86_400 * i128::from(NANOS_PER_SECOND);
+    let days = nanos.div_euclid(nanos_per_day);
+    if days.abs() > 4_000_000 { return None }
+    let (year, month, day) = civil_from_days(days as i64)?;
+    let nanos_of_day = nanos.rem_euclid(nanos_per_day);
+    let seconds = (nanos_of_day / i128::from(NANOS_PER_SECOND)) as u32;
+    let nanosecond = (nanos_of_day % i128::from(NANOS_PER_SECOND)) as u32;
+    UtcTimestamp::new(UtcDateOnly::new(year, month, day)?,
+      (seconds / 3_600) as u8, (seconds / 60 % 60) as u8, (seconds % 60) as u8, nanosecond)
+  }
+
+  /// Returns current time with millisecond precision
+  pub fn now() -> UtcTimestamp {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH)
+      .map(|duration| duration.as_nanos() as i128)
+      .unwrap_or(0);
+    UtcTimestamp::from_unix_timestamp_nanos(nanos)
+      .expect("System time is out of UTCTimestamp range")
+      .with_precision(TimestampPrecision::Milliseconds)
+  }
+
+  /// Returns time elapsed since `earlier` timestamp, `None` when `earlier` is
+  /// later than this timestamp
+  pub fn duration_since(&self, earlier: &UtcTimestamp) -> Option<Duration> {
+    let nanos = self.unix_timestamp_nanos() - earlier.unix_timestamp_nanos();
+    if nanos < 0 { return None }
+    let seconds = (nanos / i128::from(NANOS_PER_SECOND)) as u64;
+    Some(Duration::new(seconds, (nanos % i128::from(NANOS_PER_SECOND)) as u32))
+  }
+
+  fn key(&self) -> (i128, bool) {
+    (self.unix_timestamp_nanos(), self.second != 60)
+  }
+}
+
+// Proleptic Gregorian calendar date of days since `1970-01-01`, `None` when
+// the year is out of `0 - 9999` range
+fn civil_from_days(days: i64) -> Option<(u16, u8, u8)> {
+  let days = days + 719_468;
+  let era = days.div_euclid(146_097);
+  let day_of_era = days - era * 146_097;
+  let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+  let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+  let month_index = (5 * day_of_year + 2) / 153;
+  let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+  let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+  let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+  if !(0..=9_999).contains(&year) { return None }
+  Some((year as u16, month as u8, day as u8))
+}
+
+// Days since `1970-01-01` of proleptic Gregorian calendar date
+fn days_from_civil(year: u16, month: u8, day: u8) -> i64 {
+  let year = i64::from(year) - if month <= 2 { 1 } else { 0 };
+  let era = year.div_euclid(400);
+  let year_of_era = year - era * 400;
+  let month = i64::from(month);
+  let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+  let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+  era * 146_097 + day_of_era - 719_468
+}
+
+impl PartialEq for UtcTimestamp {
+  fn eq(&self, other: &UtcTimestamp) -> bool {
+    self.key() == other.key()
+  }
+}
+
+impl Eq for UtcTimestamp {}
+
+impl PartialOrd for UtcTimestamp {
+  fn partial_cmp(&self, other: &UtcTimestamp) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for UtcTimestamp {
+  fn cmp(&self, other: &UtcTimestamp) -> Ordering {
+    self.key().cmp(&other.key())
+  }
+}
+
+impl Hash for UtcTimestamp {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.key().hash(state)
+  }
+}
+
+// Parses `HH:MM:SS[.sss|.ssssss|.sssssssss]`
+fn parse_time(value: &[u8]) -> Option<(u8, u8, u8, u32, TimestampPrecision)> {
+  if value.len() < 8 || value[2] != b':' || value[5] != b':' { return None }
+  let hour = parse_number(&value[..2])? as u8;
+  let minute = parse_number(&value[3..5])? as u8;
+  let second = parse_number(&value[6..8])? as u8;
+  let (nanosecond, precision) = match &value[8..] {
+    b"" => (0, TimestampPrecision::Seconds),
+    fraction if fraction[0] == b'.' => {
+      let precision = TimestampPrecision::from_digits(fraction.len() - 1)?;
+      (parse_number(&fraction[1..])? * precision.unit(), precision)
+    },
+    _ => return None,
+  };
+  Some((hour, minute, second, nanosecond, precision))
+}
+
+impl FromFixValue for UtcTimestamp {
+  fn from_fix_value(value: &[u8]) -> Result<UtcTimestamp, FIXValueError> {
+    if value.len() < 9 || value[8] != b'-' { return Err(FIXValueError::InvalidUtcTimestamp) }
+    let date = UtcDateOnly::from_fix_value(&value[..8]).map_err(|_| FIXValueError::InvalidUtcTimestamp)?;
+    parse_time(&value[9..])
+      .and_then(|(hour, minute, second, nanosecond, precision)| {
+        UtcTimestamp::new(date, hour, minute, second, nanosecond).map(|timestamp| timestamp.with_precision(precision))
+      })
+      .ok_or(FIXValueError::InvalidUtcTimestamp)
+  }
+}
+
+impl fmt::Display for UtcTimestamp {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}-{:02}:{:02}:{:02}", self.date, self.hour, self.minute, self.second)?;
+    match self.precision.digits() {
+      0 => Ok(()),
+      digits => write!(f, ".{:0width$}", self.nanosecond / self.precision.unit(), width = digits),
+    }
+  }
+}
+
+/// Period of `MonthYear` value
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum MonthYearPeriod {
+  /// The whole month, `YYYYMM`
+  Month,
+  /// Day of the month, `YYYYMMDD`
+  Day(u8),
+  /// Week of the month, `YYYYMMwN`
+  Week(u8),
+}
+
+/// FIX `MonthYear` value in `YYYYMM`, `YYYYMMDD` or `YYYYMMwN` format
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let month_year = MonthYear::from_fix_value(b"202403w2").unwrap();
+///
+/// assert_eq!((month_year.year(), month_year.month()), (2024, 3));
+/// assert_eq!(month_year.period(), MonthYearPeriod::Week(2));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub struct MonthYear {
+  year: u16,
+  month: u8,
+  period: MonthYearPeriod,
+}
+
+impl MonthYear {
+  pub fn year(&self) -> u16 {
+    self.year
+  }
+
+  pub fn month(&self) -> u8 {
+    self.month
+  }
+
+  pub fn period(&self) -> MonthYearPeriod {
+    self.period
+  }
+}
+
+impl FromFixValue for MonthYear {
+  fn from_fix_value(value: &[u8]) -> Result<MonthYear, FIXValueError> {
+    let parse = || {
+      if value.len() != 6 && value.len() != 8 { return None }
+      let year = parse_number(&value[..4])? as u16;
+      let month = parse_number(&value[4..6])? as u8;
+      if !(1..=12).contains(&month) { return None }
+      let period = match &value[6..] {
+        b"" => MonthYearPeriod::Month,
+        [b'w', week] if (b'1'..=b'5').contains(week) => MonthYearPeriod::Week(week - b'0'),
+        day => {
+          let (_, _, day) = parse_date(&[&value[..6], day].concat())?;
+          MonthYearPeriod::Day(day)
+        },
+      };
+      Some(MonthYear { year, month, period })
+    };
+    parse().ok_or(FIXValueError::InvalidMonthYear)
+  }
+}
+
+impl fmt::Display for MonthYear {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{:04}{:02}", self.year, self.month)?;
+    match self.period {
+      MonthYearPeriod::Month => Ok(()),
+      MonthYearPeriod::Day(day) => write!(f, "{:02}", day),
+      MonthYearPeriod::Week(week) => write!(f, "w{}", week),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_convert_date_values() {
+    let date = UtcDateOnly::from_fix_value(b"20240229").unwrap();
+
+    assert_eq!((date.year(), date.month(), date.day()), (2024, 2, 29));
+    assert_eq!(date.to_string(), "20240229");
+    assert_eq!(UtcDateOnly::from_fix_value(b"20230229"), Err(FIXValueError::InvalidUtcDateOnly));
+    assert_eq!(UtcDateOnly::from_fix_value(b"20231301"), Err(FIXValueError::InvalidUtcDateOnly));
+    assert_eq!(UtcDateOnly::from_fix_value(b"2023-01-01"), Err(FIXValueError::InvalidUtcDateOnly));
+    assert_eq!(LocalMktDate::from_fix_value(b"20231131"), Err(FIXValueError::InvalidLocalMktDate));
+    assert_eq!(LocalMktDate::from_fix_value(b"20231130"), Ok(LocalMktDate::new(2023, 11, 30).unwrap()));
+  }
+
+  #[test]
+  fn it_should_convert_timestamp_values() {
+    let timestamp = UtcTimestamp::from_fix_value(b"19980604-07:58:28").unwrap();
+
+    assert_eq!(timestamp, UtcTimestamp::new(UtcDateOnly::new(1998, 6, 4).unwrap(), 7, 58, 28, 0).unwrap());
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604-23:59:60.999").unwrap().millisecond(), 999);
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604-24:00:00"), Err(FIXValueError::InvalidUtcTimestamp));
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604-07:60:28"), Err(FIXValueError::InvalidUtcTimestamp));
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604 07:58:28"), Err(FIXValueError::InvalidUtcTimestamp));
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604-07:58:28."), Err(FIXValueError::InvalidUtcTimestamp));
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980631-07:58:28"), Err(FIXValueError::InvalidUtcTimestamp));
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604-07:58:28.1234"), Err(FIXValueError::InvalidUtcTimestamp));
+  }
+
+  #[test]
+  fn it_should_preserve_timestamp_precision() {
+    for value in &["19980604-07:58:28", "19980604-07:58:28.000", "19980604-07:58:28.000120",
+      "19980604-07:58:28.000000005", "20161231-23:59:60.500"] {
+      assert_eq!(UtcTimestamp::from_fix_value(value.as_bytes()).unwrap().to_string(), *value);
+    }
+
+    let timestamp = UtcTimestamp::from_fix_value(b"19980604-07:58:28.123456789").unwrap();
+
+    assert_eq!((timestamp.millisecond(), timestamp.microsecond()), (123, 123_456));
+    assert_eq!(timestamp.with_precision(TimestampPrecision::Microseconds).to_string(), "19980604-07:58:28.123456");
+    assert_eq!(timestamp.with_precision(TimestampPrecision::Seconds).to_string(), "19980604-07:58:28");
+    assert_eq!(UtcTimestamp::from_fix_value(b"19980604-07:58:28.000").unwrap(),
+      UtcTimestamp::from_fix_value(b"19980604-07:58:28").unwrap());
+  }
+
+  #[test]
+  fn it_should_infer_timestamp_precision() {
+    let date = UtcDateOnly::new(1998, 6, 4).unwrap();
+
+    assert_eq!(UtcTimestamp::new(date, 0, 0, 0, 0).unwrap().precision(), TimestampPrecision::Seconds);
+    assert_eq!(UtcTimestamp::new(date, 0, 0, 0, 5_000_000).unwrap().precision(), TimestampPrecision::Milliseconds);
+    assert_eq!(UtcTimestamp::new(date, 0, 0, 0, 5_000).unwrap().precision(), TimestampPrecision::Microseconds);
+    assert_eq!(UtcTimestamp::new(date, 0, 0, 0, 5).unwrap().precision(), TimestampPrecision::Nanoseconds);
+    assert_eq!(UtcTimestamp::new(date, 0, 0, 0, 1_000_000_000), None);
+  }
+
+  #[test]
+  fn it_should_calculate_timestamp_durations() {
+    let epoch = UtcTimestamp::from_fix_value(b"19700101-00:00:00").unwrap();
+    let leap_day = UtcTimestamp::from_fix_value(b"20000229-23:59:59.999999999").unwrap();
+    let year_zero = UtcTimestamp::from_fix_value(b"00000301-00:00:00").unwrap();
+
+    assert_eq!(epoch.unix_timestamp_nanos(), 0);
+    assert_eq!(leap_day.unix_timestamp_nanos(), 951_868_799_999_999_999);
+    assert_eq!(year_zero.unix_timestamp_nanos(), -62_162_035_200 * 1_000_000_000);
+    assert_eq!(leap_day.duration_since(&epoch), Some(Duration::new(951_868_799, 999_999_999)));
+    assert_eq!(epoch.duration_since(&leap_day), None);
+    assert!(epoch < leap_day);
+
+    assert_eq!(UtcTimestamp::from_unix_timestamp_nanos(951_868_799_999_999_999).unwrap().to_string(),
+      "20000229-23:59:59.999999999");
+    assert_eq!(UtcTimestamp::from_unix_timestamp_nanos(-62_162_035_200 * 1_000_000_000), Some(year_zero));
+    assert_eq!(UtcTimestamp::from_unix_timestamp_nanos(-62_167_219_201 * 1_000_000_000), None);
+    assert_eq!(UtcTimestamp::now().precision(), TimestampPrecision::Milliseconds);
+  }
+
+  #[test]
+  fn it_should_order_leap_second_by_unix_timestamp() {
+    let leap_second = UtcTimestamp::from_fix_value(b"20161231-23:59:60.500").unwrap();
+    let before = UtcTimestamp::from_fix_value(b"20170101-00:00:00.200").unwrap();
+    let same_nanos = UtcTimestamp::from_fix_value(b"20170101-00:00:00.500").unwrap();
+
+    assert!(leap_second > before);
+    assert!(leap_second.unix_timestamp_nanos() > before.unix_timestamp_nanos());
+    assert_eq!(leap_second.duration_since(&before), Some(Duration::from_millis(300)));
+    assert_eq!(leap_second.unix_timestamp_nanos(), same_nanos.unix_timestamp_nanos());
+    assert_ne!(leap_second, same_nanos);
+    assert!(leap_second < same_nanos);
+  }
+
+  #[test]
+  fn it_should_convert_month_year_values() {
+    assert_eq!(MonthYear::from_fix_value(b"202403").unwrap().period(), MonthYearPeriod::Month);
+    assert_eq!(MonthYear::from_fix_value(b"20240331").unwrap().period(), MonthYearPeriod::Day(31));
+    assert_eq!(MonthYear::from_fix_value(b"202403w5").unwrap().to_string(), "202403w5");
+    assert_eq!(MonthYear::from_fix_value(b"202402").unwrap().to_string(), "202402");
+    assert_eq!(MonthYear::from_fix_value(b"202403w6"), Err(FIXValueError::InvalidMonthYear));
+    assert_eq!(MonthYear::from_fix_value(b"20240230"), Err(FIXValueError::InvalidMonthYear));
+    assert_eq!(MonthYear::from_fix_value(b"202413"), Err(FIXValueError::InvalidMonthYear));
+  }
+}
@@ -0,0 +1,316 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+use super::super::FIXValueError;
+use super::{FromFixValue, ToFixValue, is_float};
+
+/// Exact decimal value of FIX `float` fields like `Price`, `Qty` or `Amt`
+///
+/// Value is stored as integer `mantissa` and `scale`, the number of digits
+/// after the decimal point, so `100.2500` is `1002500` with scale `4`. Scale of
+/// parsed value is preserved when it is formatted back. Values are equal and
+/// ordered by the number they represent regardless of scale.
+///
+/// Addition and subtraction keep the larger scale of operands, multiplication
+/// adds scales. Mantissa is limited to `-i128::MAX..=i128::MAX`, so every
+/// value can be negated. Operators panic on overflow, use `checked_add`,
+/// `checked_sub`, `checked_mul` and `checked_neg` to handle it. Division is not
+/// supported, because its result is not always exact.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let price = FixDecimal::from_fix_value(b"100.2500").unwrap();
+///
+/// assert_eq!((price.mantissa(), price.scale()), (1002500, 4));
+/// assert_eq!(price.to_string(), "100.2500");
+/// assert_eq!(price, FixDecimal::new(10025, 2));
+/// assert_eq!(price.normalize().to_string(), "100.25");
+///
+/// let qty: FixDecimal = "3".parse().unwrap();
+///
+/// assert_eq!((price * qty).to_string(), "300.7500");
+/// assert_eq!((price - FixDecimal::new(5, 1)).to_string(), "99.7500");
+/// assert!(price > "100.2499".parse().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FixDecimal {
+  mantissa: i128,
+  scale: u32,
+}
+
+impl FixDecimal {
+  /// Creates decimal `mantissa * 10^-scale`
+  ///
+  /// # Panics
+  /// Panics when `mantissa` is `i128::MIN`, use `checked_new` to handle it
+  pub fn new(mantissa: i128, scale: u32) -> FixDecimal {
+    FixDecimal::checked_new(mantissa, scale).expect("FixDecimal mantissa overflow")
+  }
+
+  /// Creates decimal `mantissa * 10^-scale`, `None` when `mantissa` is
+  /// `i128::MIN`
+  pub fn checked_new(mantissa: i128, scale: u32) -> Option<FixDecimal> {
+    if mantissa == i128::MIN { return None }
+    Some(FixDecimal { mantissa, scale })
+  }
+
+  pub fn mantissa(&self) -> i128 {
+    self.mantissa
+  }
+
+  pub fn scale(&self) -> u32 {
+    self.scale
+  }
+
+  /// Returns the same value without trailing zeros of fraction
+  pub fn normalize(&self) -> FixDecimal {
+    let mut decimal = *self;
+    while decimal.scale > 0 && decimal.mantissa % 10 == 0 {
+      decimal.mantissa /= 10;
+      decimal.scale -= 1;
+    }
+    decimal
+  }
+
+  /// Returns the same value with greater or equal `scale`, `None` when
+  /// `scale` is less than current one or mantissa overflows
+  pub fn with_scale(&self, scale: u32) -> Option<FixDecimal> {
+    let factor = 10i128.checked_pow(scale.checked_sub(self.scale)?)?;
+    FixDecimal::checked_new(self.mantissa.checked_mul(factor)?, scale)
+  }
+
+  pub fn checked_add(&self, other: &FixDecimal) -> Option<FixDecimal> {
+    let scale = self.scale.max(other.scale);
+    let mantissa = self.with_scale(scale)?.mantissa.checked_add(other.with_scale(scale)?.mantissa)?;
+    FixDecimal::checked_new(mantissa, scale)
+  }
+
+  pub fn checked_sub(&self, other: &FixDecimal) -> Option<FixDecimal> {
+    let scale = self.scale.max(other.scale);
+    let mantissa = self.with_scale(scale)?.mantissa.checked_sub(other.with_scale(scale)?.mantissa)?;
+    FixDecimal::checked_new(mantissa, scale)
+  }
+
+  pub fn checked_mul(&self, other: &FixDecimal) -> Option<FixDecimal> {
+    let mantissa = self.mantissa.checked_mul(other.mantissa)?;
+    FixDecimal::checked_new(mantissa, self.scale.checked_add(other.scale)?)
+  }
+
+  pub fn checked_neg(&self) -> Option<FixDecimal> {
+    FixDecimal::checked_new(self.mantissa.checked_neg()?, self.scale)
+  }
+
+  /// Converts value into the nearest `f64`
+  pub fn to_f64(&self) -> f64 {
+    self.mantissa as f64 / 10f64.powi(self.scale.min(i32::MAX as u32) as i32)
+  }
+}
+
+impl PartialEq for FixDecimal {
+  fn eq(&self, other: &FixDecimal) -> bool {
+    self.cmp(other) == Ordering::Equal
+  }
+}
+
+impl Eq for FixDecimal {}
+
+impl PartialOrd for FixDecimal {
+  fn partial_cmp(&self, other: &FixDecimal) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for FixDecimal {
+  fn cmp(&self, other: &FixDecimal) -> Ordering {
+    let (this, other) = (self.normalize(), other.normalize());
+    let sign = this.mantissa.signum().cmp(&other.mantissa.signum());
+    if sign != Ordering::Equal || this.mantissa == 0 { return sign }
+
+    let scale = this.scale.max(other.scale);
+    // Values have the same sign here, mantissa which overflows when scaled is
+    // greater by absolute value than the other one
+    match (this.with_scale(scale), other.with_scale(scale)) {
+      (Some(this), Some(other)) => this.mantissa.cmp(&other.mantissa),
+      (None, _) => this.mantissa.cmp(&0),
+      (_, None) => 0.cmp(&other.mantissa),
+    }
+  }
+}
+
+impl Hash for FixDecimal {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    let decimal = self.normalize();
+    decimal.mantissa.hash(state);
+    decimal.scale.hash(state);
+  }
+}
+
+impl Add for FixDecimal {
+  type Output = FixDecimal;
+
+  fn add(self, other: FixDecimal) -> FixDecimal {
+    self.checked_add(&other).expect("FixDecimal addition overflow")
+  }
+}
+
+impl Sub for FixDecimal {
+  type Output = FixDecimal;
+
+  fn sub(self, other: FixDecimal) -> FixDecimal {
+    self.checked_sub(&other).expect("FixDecimal subtraction overflow")
+  }
+}
+
+impl Mul for FixDecimal {
+  type Output = FixDecimal;
+
+  fn mul(self, other: FixDecimal) -> FixDecimal {
+    self.checked_mul(&other).expect("FixDecimal multiplication overflow")
+  }
+}
+
+impl Neg for FixDecimal {
+  type Output = FixDecimal;
+
+  fn neg(self) -> FixDecimal {
+    self.checked_neg().expect("FixDecimal negation overflow")
+  }
+}
+
+impl From<i64> for FixDecimal {
+  fn from(value: i64) -> FixDecimal {
+    FixDecimal::new(i128::from(value), 0)
+  }
+}
+
+impl FromFixValue for FixDecimal {
+  fn from_fix_value(value: &[u8]) -> Result<FixDecimal, FIXValueError> {
+    if !is_float(value) { return Err(FIXValueError::InvalidFloat) }
+    let (sign, digits) = match value.split_first() {
+      Some((&b'-', digits)) => (-1, digits),
+      _ => (1, value),
+    };
+
+    let mut decimal = FixDecimal::new(0, 0);
+    let mut fraction = false;
+    for &byte in digits {
+      if byte == b'.' {
+        fraction = true;
+        continue
+      }
+      decimal.mantissa = decimal.mantissa.checked_mul(10)
+        .and_then(|mantissa| mantissa.checked_add(sign * i128::from(byte - b'0')))
+        .ok_or(FIXValueError::DecimalOverflow)?;
+      if fraction { decimal.scale += 1 }
+    }
+    FixDecimal::checked_new(decimal.mantissa, decimal.scale).ok_or(FIXValueError::DecimalOverflow)
+  }
+}
+
+impl ToFixValue for FixDecimal {
+  fn to_fix_value(&self) -> Vec<u8> {
+    self.to_string().into_bytes()
+  }
+}
+
+impl FromStr for FixDecimal {
+  type Err = FIXValueError;
+
+  fn from_str(value: &str) -> Result<FixDecimal, FIXValueError> {
+    FixDecimal::from_fix_value(value.as_bytes())
+  }
+}
+
+impl fmt::Display for FixDecimal {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    let digits = self.mantissa.unsigned_abs().to_string();
+    let scale = self.scale as usize;
+    let sign = if self.mantissa < 0 { "-" } else { "" };
+    if scale == 0 { return write!(f, "{}{}", sign, digits) }
+
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    write!(f, "{}{}.{}", sign, integer, fraction)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_convert_decimal_values() {
+    assert_eq!(FixDecimal::from_fix_value(b"-0.05").unwrap(), FixDecimal::new(-5, 2));
+    assert_eq!(FixDecimal::from_fix_value(b"15."), Ok(FixDecimal::new(15, 0)));
+    assert_eq!(FixDecimal::from_fix_value(b".5").unwrap().to_string(), "0.5");
+    assert_eq!(FixDecimal::from_fix_value(b"1.5e3"), Err(FIXValueError::InvalidFloat));
+    assert_eq!(FixDecimal::from_fix_value(&[b'9'; 40]), Err(FIXValueError::DecimalOverflow));
+    assert_eq!(FixDecimal::from_str(&i128::MIN.to_string()), Err(FIXValueError::DecimalOverflow));
+    assert_eq!(FixDecimal::from_str(&(i128::MIN + 1).to_string()), Ok(FixDecimal::new(i128::MIN + 1, 0)));
+  }
+
+  #[test]
+  fn it_should_format_decimal_values_with_scale() {
+    assert_eq!(FixDecimal::new(1002500, 4).to_string(), "100.2500");
+    assert_eq!(FixDecimal::new(-5, 3).to_string(), "-0.005");
+    assert_eq!(FixDecimal::new(-12, 0).to_string(), "-12");
+    assert_eq!(FixDecimal::new(-i128::MAX, 0).to_string(), (-i128::MAX).to_string());
+  }
+
+  #[test]
+  fn it_should_compare_decimal_values_regardless_of_scale() {
+    assert_eq!(FixDecimal::new(1002500, 4), FixDecimal::new(10025, 2));
+    assert_ne!(FixDecimal::new(1002500, 4), FixDecimal::new(10025, 3));
+    assert_eq!(FixDecimal::new(0, 4), FixDecimal::new(0, 0));
+  }
+
+  #[test]
+  fn it_should_order_decimal_values() {
+    let mut values: Vec<FixDecimal> = ["1.5", "-0.25", "1.50", "-1", "0", "1.499"].iter()
+      .map(|value| value.parse().unwrap())
+      .collect();
+    values.sort();
+
+    assert_eq!(values.iter().map(FixDecimal::to_string).collect::<Vec<_>>(),
+      vec!["-1", "-0.25", "0", "1.499", "1.5", "1.50"]);
+    assert!(FixDecimal::new(i128::MAX, 0) > FixDecimal::new(1, 30));
+    assert!(FixDecimal::new(-i128::MAX, 0) < FixDecimal::new(-1, 30));
+    assert!(FixDecimal::new(1, 0) > FixDecimal::new(i128::MAX, 60));
+    assert!(FixDecimal::new(0, 0) < FixDecimal::new(1, 60));
+    assert!(FixDecimal::new(0, 5) > FixDecimal::new(-1, 60));
+    assert!(FixDecimal::new(-1, 0) < FixDecimal::new(-1, 60));
+  }
+
+  #[test]
+  fn it_should_calculate_decimal_values() {
+    let price = FixDecimal::new(1002500, 4);
+
+    assert_eq!((price + FixDecimal::new(5, 1)).to_string(), "100.7500");
+    assert_eq!((FixDecimal::new(5, 1) - price).to_string(), "-99.7500");
+    assert_eq!((price * FixDecimal::from(-2)).to_string(), "-200.5000");
+    assert_eq!((-price).to_string(), "-100.2500");
+    assert_eq!(FixDecimal::new(i128::MAX, 0).checked_add(&FixDecimal::new(1, 0)), None);
+    assert_eq!(FixDecimal::new(1, 0).checked_sub(&FixDecimal::new(1, 40)), None);
+    assert_eq!(FixDecimal::new(i128::MAX, 0).checked_mul(&FixDecimal::new(2, 0)), None);
+    assert_eq!(FixDecimal::new(-i128::MAX, 0).checked_sub(&FixDecimal::new(1, 0)), None);
+    assert_eq!(FixDecimal::new(-i128::MAX, 0).checked_neg(), Some(FixDecimal::new(i128::MAX, 0)));
+    assert_eq!(FixDecimal::checked_new(i128::MIN, 0), None);
+    assert_eq!(price.to_f64(), 100.25);
+  }
+
+  #[test]
+  fn it_should_rescale_decimal_values() {
+    let price = FixDecimal::new(1002500, 4);
+
+    assert_eq!(price.normalize().scale(), 2);
+    assert_eq!(price.with_scale(6).unwrap().to_string(), "100.250000");
+    assert_eq!(price.with_scale(2), None);
+    assert_eq!(FixDecimal::new(0, 3).normalize().to_string(), "0");
+  }
+}
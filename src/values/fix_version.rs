@@ -0,0 +1,230 @@
+use std::fmt;
+use std::str;
+
+use super::super::FIXValueError;
+use super::super::fix_message_fields::{APPL_EXT_ID, APPL_VER_ID, CSTM_APPL_VER_ID, DEFAULT_APPL_VER_ID, DEFAULT_CSTM_APPL_VER_ID,
+  DELIVER_TO_LOCATION_ID, HOP_COMP_ID, HOP_REF_ID, HOP_SENDING_TIME, LAST_MSG_SEQ_NUM_PROCESSED, MESSAGE_ENCODING,
+  NO_HOPS, ON_BEHALF_OF_LOCATION_ID, ON_BEHALF_OF_SENDING_TIME, SENDER_LOCATION_ID, TARGET_LOCATION_ID, XML_DATA,
+  XML_DATA_LEN};
+use super::FromFixValue;
+
+/// `8 - BeginString` of FIX message
+///
+/// Versions are ordered by their release, FIX 5.0 and later use `FIXT.1.1`
+/// session protocol and carry application version in `1128 - ApplVerID` or
+/// `1137 - DefaultApplVerID` of `Logon`, see `ApplVerId`.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// assert_eq!(FixVersion::from_begin_string("FIX.4.4"), Some(FixVersion::Fix44));
+/// assert_eq!(FixVersion::from_begin_string("FIX.5.0"), None);
+/// assert_eq!(FixVersion::Fixt11.as_str(), "FIXT.1.1");
+/// assert!(FixVersion::Fix40 < FixVersion::Fix42);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum FixVersion {
+  /// `FIX.4.0`
+  Fix40,
+  /// `FIX.4.1`
+  Fix41,
+  /// `FIX.4.2`
+  Fix42,
+  /// `FIX.4.3`
+  Fix43,
+  /// `FIX.4.4`
+  Fix44,
+  /// `FIXT.1.1`, session protocol of FIX 5.0 and later
+  Fixt11,
+}
+
+const FIX_VERSIONS: [(FixVersion, &str); 6] = [
+  (FixVersion::Fix40, "FIX.4.0"),
+  (FixVersion::Fix41, "FIX.4.1"),
+  (FixVersion::Fix42, "FIX.4.2"),
+  (FixVersion::Fix43, "FIX.4.3"),
+  (FixVersion::Fix44, "FIX.4.4"),
+  (FixVersion::Fixt11, "FIXT.1.1"),
+];
+
+// Header fields which are not defined by every version, with the version
+// which introduced them
+const VERSION_HEADER_FIELDS: [(u32, FixVersion); 18] = [
+  (SENDER_LOCATION_ID, FixVersion::Fix41),
+  (TARGET_LOCATION_ID, FixVersion::Fix41),
+  (ON_BEHALF_OF_LOCATION_ID, FixVersion::Fix41),
+  (DELIVER_TO_LOCATION_ID, FixVersion::Fix41),
+  (XML_DATA_LEN, FixVersion::Fix42),
+  (XML_DATA, FixVersion::Fix42),
+  (MESSAGE_ENCODING, FixVersion::Fix42),
+  (LAST_MSG_SEQ_NUM_PROCESSED, FixVersion::Fix42),
+  (ON_BEHALF_OF_SENDING_TIME, FixVersion::Fix42),
+  (NO_HOPS, FixVersion::Fix43),
+  (HOP_COMP_ID, FixVersion::Fix43),
+  (HOP_SENDING_TIME, FixVersion::Fix43),
+  (HOP_REF_ID, FixVersion::Fix43),
+  (APPL_VER_ID, FixVersion::Fixt11),
+  (CSTM_APPL_VER_ID, FixVersion::Fixt11),
+  (DEFAULT_APPL_VER_ID, FixVersion::Fixt11),
+  (APPL_EXT_ID, FixVersion::Fixt11),
+  (DEFAULT_CSTM_APPL_VER_ID, FixVersion::Fixt11),
+];
+
+impl FixVersion {
+  /// Returns `8 - BeginString` value
+  pub fn as_str(&self) -> &'static str {
+    FIX_VERSIONS.iter().find(|&&(version, _)| version == *self).map(|&(_, value)| value).unwrap()
+  }
+
+  /// Returns version of `8 - BeginString` value, `None` when the version is
+  /// unknown
+  pub fn from_begin_string(value: &str) -> Option<FixVersion> {
+    FIX_VERSIONS.iter().find(|&&(_, begin_string)| begin_string == value).map(|&(version, _)| version)
+  }
+
+  /// Returns `false` when `tag` is a header field introduced by a later
+  /// version, e.g. `1128 - ApplVerID` is defined only by `FIXT.1.1`
+  pub fn defines_field(&self, tag: u32) -> bool {
+    VERSION_HEADER_FIELDS.iter()
+      .all(|&(field_tag, since)| field_tag != tag || *self >= since)
+  }
+
+  /// Returns fields the version requires in messages of `msg_type` in
+  /// addition to the standard header, e.g. `1137 - DefaultApplVerID` of
+  /// `FIXT.1.1` `Logon`
+  pub fn required_fields(&self, msg_type: &[u8]) -> &'static [u32] {
+    match (*self, msg_type) {
+      (FixVersion::Fixt11, b"A") => &[DEFAULT_APPL_VER_ID],
+      _ => &[],
+    }
+  }
+}
+
+impl fmt::Display for FixVersion {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+impl FromFixValue for FixVersion {
+  fn from_fix_value(value: &[u8]) -> Result<FixVersion, FIXValueError> {
+    let value = str::from_utf8(value).map_err(|_| FIXValueError::InvalidEncoding)?;
+    FixVersion::from_begin_string(value).ok_or(FIXValueError::InvalidEnumValue)
+  }
+}
+
+/// `1128 - ApplVerID` and `1137 - DefaultApplVerID` of FIX message
+///
+/// Application version of messages sent over `FIXT.1.1` session.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// assert_eq!(ApplVerId::from_code("9"), Some(ApplVerId::Fix50Sp2));
+/// assert_eq!(ApplVerId::Fix44.as_str(), "6");
+/// assert_eq!(ApplVerId::Fix50Sp1.version_name(), "FIX.5.0SP1");
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum ApplVerId {
+  /// `0` - FIX 2.7
+  Fix27,
+  /// `1` - FIX 3.0
+  Fix30,
+  /// `2` - FIX 4.0
+  Fix40,
+  /// `3` - FIX 4.1
+  Fix41,
+  /// `4` - FIX 4.2
+  Fix42,
+  /// `5` - FIX 4.3
+  Fix43,
+  /// `6` - FIX 4.4
+  Fix44,
+  /// `7` - FIX 5.0
+  Fix50,
+  /// `8` - FIX 5.0 SP1
+  Fix50Sp1,
+  /// `9` - FIX 5.0 SP2
+  Fix50Sp2,
+}
+
+const APPL_VER_IDS: [(ApplVerId, &str, &str); 10] = [
+  (ApplVerId::Fix27, "0", "FIX.2.7"),
+  (ApplVerId::Fix30, "1", "FIX.3.0"),
+  (ApplVerId::Fix40, "2", "FIX.4.0"),
+  (ApplVerId::Fix41, "3", "FIX.4.1"),
+  (ApplVerId::Fix42, "4", "FIX.4.2"),
+  (ApplVerId::Fix43, "5", "FIX.4.3"),
+  (ApplVerId::Fix44, "6", "FIX.4.4"),
+  (ApplVerId::Fix50, "7", "FIX.5.0"),
+  (ApplVerId::Fix50Sp1, "8", "FIX.5.0SP1"),
+  (ApplVerId::Fix50Sp2, "9", "FIX.5.0SP2"),
+];
+
+impl ApplVerId {
+  /// Returns `1128 - ApplVerID` value
+  pub fn as_str(&self) -> &'static str {
+    APPL_VER_IDS.iter().find(|&&(id, _, _)| id == *self).map(|&(_, code, _)| code).unwrap()
+  }
+
+  /// Returns application version name as used by data dictionaries, e.g.
+  /// `FIX.5.0SP2`
+  pub fn version_name(&self) -> &'static str {
+    APPL_VER_IDS.iter().find(|&&(id, _, _)| id == *self).map(|&(_, _, name)| name).unwrap()
+  }
+
+  /// Returns application version of `1128 - ApplVerID` value, `None` when
+  /// the value is unknown
+  pub fn from_code(value: &str) -> Option<ApplVerId> {
+    APPL_VER_IDS.iter().find(|&&(_, code, _)| code == value).map(|&(id, _, _)| id)
+  }
+
+  /// Returns application version of data dictionary version name, `None`
+  /// when the name is unknown
+  pub fn from_version_name(value: &str) -> Option<ApplVerId> {
+    APPL_VER_IDS.iter().find(|&&(_, _, name)| name == value).map(|&(id, _, _)| id)
+  }
+}
+
+impl fmt::Display for ApplVerId {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+impl FromFixValue for ApplVerId {
+  fn from_fix_value(value: &[u8]) -> Result<ApplVerId, FIXValueError> {
+    let value = str::from_utf8(value).map_err(|_| FIXValueError::InvalidEncoding)?;
+    ApplVerId::from_code(value).ok_or(FIXValueError::InvalidEnumValue)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_define_header_fields_by_version() {
+    assert!(FixVersion::Fix40.defines_field(49));
+    assert!(!FixVersion::Fix40.defines_field(SENDER_LOCATION_ID));
+    assert!(FixVersion::Fix41.defines_field(DELIVER_TO_LOCATION_ID));
+    assert!(!FixVersion::Fix40.defines_field(MESSAGE_ENCODING));
+    assert!(FixVersion::Fix42.defines_field(MESSAGE_ENCODING));
+    assert!(!FixVersion::Fix42.defines_field(NO_HOPS));
+    assert!(!FixVersion::Fix44.defines_field(APPL_VER_ID));
+    assert!(FixVersion::Fixt11.defines_field(APPL_VER_ID));
+  }
+
+  #[test]
+  fn it_should_convert_versions_from_fix_values() {
+    assert_eq!(FixVersion::from_fix_value(b"FIXT.1.1"), Ok(FixVersion::Fixt11));
+    assert_eq!(FixVersion::from_fix_value(b"FIX.4.5"), Err(FIXValueError::InvalidEnumValue));
+    assert_eq!(ApplVerId::from_fix_value(b"7"), Ok(ApplVerId::Fix50));
+    assert_eq!(ApplVerId::from_fix_value(b"10"), Err(FIXValueError::InvalidEnumValue));
+    assert_eq!(ApplVerId::from_version_name("FIX.5.0SP2"), Some(ApplVerId::Fix50Sp2));
+  }
+}
@@ -0,0 +1,249 @@
+use std::str;
+
+use super::FIXValueError;
+
+pub use self::datetime::*;
+pub use self::decimal::*;
+pub use self::fix_version::*;
+pub use self::msg_type::*;
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions;
+mod datetime;
+mod decimal;
+mod fix_version;
+mod msg_type;
+#[cfg(feature = "time")]
+mod time_conversions;
+
+/// This trait converts value of FIX message field into a typed value
+///
+/// It is implemented for FIX data types: `int` (`i32`, `i64`, `u32`, `u64`,
+/// `usize`),
+/// `float` (`f64`, or `FixDecimal` for exact `Price`, `Qty`, `Amt` values),
+/// `char`, `Boolean` (`bool`), `String`, `MultipleValueString`
+/// (`Vec<String>`), `UTCTimestamp` (`UtcTimestamp`), `UTCDateOnly`
+/// (`UtcDateOnly`), `LocalMktDate` (`LocalMktDate`) and `MonthYear`
+/// (`MonthYear`).
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// assert_eq!(i64::from_fix_value(b"-42"), Ok(-42));
+/// assert_eq!(bool::from_fix_value(b"Y"), Ok(true));
+/// assert_eq!(bool::from_fix_value(b"yes"), Err(FIXValueError::InvalidBoolean));
+/// assert_eq!(FixDecimal::from_fix_value(b"100.2500").unwrap().to_string(), "100.2500");
+/// ```
+pub trait FromFixValue: Sized {
+  fn from_fix_value(value: &[u8]) -> Result<Self, FIXValueError>;
+}
+
+/// This trait converts a typed value into value of FIX message field, it is
+/// implemented for the same types as `FromFixValue` except `f64`
+///
+/// Values are formatted the way FIX data types require, `FixDecimal` and
+/// `UtcTimestamp` keep their scale and precision. Use
+/// `FIXMessageField::from_value` to create a field.
+///
+/// # Examples
+///
+/// ```
+/// use fix_message::*;
+///
+/// let price = FixDecimal::from_fix_value(b"100.2500").unwrap();
+///
+/// assert_eq!(price.to_fix_value(), b"100.2500");
+/// assert_eq!(true.to_fix_value(), b"Y");
+/// assert_eq!(FIXMessageField::from_value(44, &price).value, b"100.2500");
+/// ```
+pub trait ToFixValue {
+  fn to_fix_value(&self) -> Vec<u8>;
+}
+
+pub(crate) fn is_digits(value: &[u8]) -> bool {
+  !value.is_empty() && value.iter().all(u8::is_ascii_digit)
+}
+
+// Checks `[-]<digits>` format of FIX `int` value
+fn int_str(value: &[u8], signed: bool) -> Result<&str, FIXValueError> {
+  let digits = match value.first() {
+    Some(&b'-') if signed => &value[1..],
+    _ => value,
+  };
+  if !is_digits(digits) { return Err(FIXValueError::InvalidInt) }
+  str::from_utf8(value).map_err(|_| FIXValueError::InvalidInt)
+}
+
+macro_rules! impl_from_fix_value_for_int {
+  ($($int:ty: $signed:expr),*) => {
+    $(
+      impl FromFixValue for $int {
+        fn from_fix_value(value: &[u8]) -> Result<$int, FIXValueError> {
+          int_str(value, $signed)?.parse().map_err(|_| FIXValueError::InvalidInt)
+        }
+      }
+    )*
+  }
+}
+
+impl_from_fix_value_for_int!(i32: true, i64: true, u32: false, u64: false, usize: false);
+
+macro_rules! impl_to_fix_value_for_display {
+  ($($type:ty),*) => {
+    $(
+      impl ToFixValue for $type {
+        fn to_fix_value(&self) -> Vec<u8> {
+          self.to_string().into_bytes()
+        }
+      }
+    )*
+  }
+}
+
+impl_to_fix_value_for_display!(i32, i64, u32, u64, usize, char, str, String, UtcTimestamp, UtcDateOnly, LocalMktDate,
+  MonthYear, MsgType, FixVersion, ApplVerId);
+
+impl FromFixValue for f64 {
+  fn from_fix_value(value: &[u8]) -> Result<f64, FIXValueError> {
+    if !is_float(value) { return Err(FIXValueError::InvalidFloat) }
+    str::from_utf8(value).ok()
+      .and_then(|value| value.parse().ok())
+      .ok_or(FIXValueError::InvalidFloat)
+  }
+}
+
+/// Checks `[-]<digits>[.<digits>]` format of FIX `float` value, digits may be
+/// omitted on one side of the point
+pub(crate) fn is_float(value: &[u8]) -> bool {
+  let value = value.strip_prefix(b"-").unwrap_or(value);
+  let mut parts = value.splitn(2, |&byte| byte == b'.');
+  let integer = parts.next().unwrap_or(b"");
+  let fraction = parts.next().unwrap_or(b"");
+  (!integer.is_empty() || !fraction.is_empty()) &&
+    integer.iter().chain(fraction).all(u8::is_ascii_digit)
+}
+
+impl FromFixValue for char {
+  fn from_fix_value(value: &[u8]) -> Result<char, FIXValueError> {
+    match *value {
+      [byte] if byte.is_ascii_graphic() || byte == b' ' => Ok(char::from(byte)),
+      _ => Err(FIXValueError::InvalidChar),
+    }
+  }
+}
+
+impl FromFixValue for bool {
+  fn from_fix_value(value: &[u8]) -> Result<bool, FIXValueError> {
+    match value {
+      b"Y" => Ok(true),
+      b"N" => Ok(false),
+      _ => Err(FIXValueError::InvalidBoolean),
+    }
+  }
+}
+
+impl ToFixValue for bool {
+  fn to_fix_value(&self) -> Vec<u8> {
+    if *self { b"Y".to_vec() } else { b"N".to_vec() }
+  }
+}
+
+impl FromFixValue for String {
+  fn from_fix_value(value: &[u8]) -> Result<String, FIXValueError> {
+    str::from_utf8(value).map(str::to_string).map_err(|_| FIXValueError::InvalidEncoding)
+  }
+}
+
+/// `MultipleValueString`, values are separated by spaces
+impl FromFixValue for Vec<String> {
+  fn from_fix_value(value: &[u8]) -> Result<Vec<String>, FIXValueError> {
+    let value = str::from_utf8(value).map_err(|_| FIXValueError::InvalidEncoding)?;
+    Ok(value.split(' ').filter(|value| !value.is_empty()).map(str::to_string).collect())
+  }
+}
+
+/// `data`, raw bytes of the value
+impl FromFixValue for Vec<u8> {
+  fn from_fix_value(value: &[u8]) -> Result<Vec<u8>, FIXValueError> {
+    Ok(value.to_vec())
+  }
+}
+
+impl ToFixValue for [String] {
+  fn to_fix_value(&self) -> Vec<u8> {
+    self.join(" ").into_bytes()
+  }
+}
+
+impl ToFixValue for Vec<String> {
+  fn to_fix_value(&self) -> Vec<u8> {
+    self[..].to_fix_value()
+  }
+}
+
+impl ToFixValue for [u8] {
+  fn to_fix_value(&self) -> Vec<u8> {
+    self.to_vec()
+  }
+}
+
+impl ToFixValue for Vec<u8> {
+  fn to_fix_value(&self) -> Vec<u8> {
+    self.clone()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_convert_int_values() {
+    assert_eq!(i32::from_fix_value(b"00023"), Ok(23));
+    assert_eq!(i64::from_fix_value(b"-23"), Ok(-23));
+    assert_eq!(u64::from_fix_value(b"18446744073709551615"), Ok(u64::MAX));
+    assert_eq!(u32::from_fix_value(b"-23"), Err(FIXValueError::InvalidInt));
+    assert_eq!(i32::from_fix_value(b"+23"), Err(FIXValueError::InvalidInt));
+    assert_eq!(i32::from_fix_value(b"2147483648"), Err(FIXValueError::InvalidInt));
+    assert_eq!(i32::from_fix_value(b"-"), Err(FIXValueError::InvalidInt));
+    assert_eq!(i32::from_fix_value(b""), Err(FIXValueError::InvalidInt));
+  }
+
+  #[test]
+  fn it_should_convert_float_values() {
+    assert_eq!(f64::from_fix_value(b"1.5"), Ok(1.5));
+    assert_eq!(f64::from_fix_value(b"-.5"), Ok(-0.5));
+    assert_eq!(f64::from_fix_value(b"15."), Ok(15.0));
+    assert_eq!(f64::from_fix_value(b"1e5"), Err(FIXValueError::InvalidFloat));
+    assert_eq!(f64::from_fix_value(b"."), Err(FIXValueError::InvalidFloat));
+    assert_eq!(f64::from_fix_value(b"inf"), Err(FIXValueError::InvalidFloat));
+  }
+
+  #[test]
+  fn it_should_convert_char_and_boolean_values() {
+    assert_eq!(char::from_fix_value(b"A"), Ok('A'));
+    assert_eq!(char::from_fix_value(b"AB"), Err(FIXValueError::InvalidChar));
+    assert_eq!(char::from_fix_value(b"\xff"), Err(FIXValueError::InvalidChar));
+    assert_eq!(bool::from_fix_value(b"N"), Ok(false));
+    assert_eq!(bool::from_fix_value(b"y"), Err(FIXValueError::InvalidBoolean));
+  }
+
+  #[test]
+  fn it_should_convert_string_values() {
+    assert_eq!(String::from_fix_value(b"BRKR"), Ok("BRKR".to_string()));
+    assert_eq!(String::from_fix_value(b"\xff"), Err(FIXValueError::InvalidEncoding));
+    assert_eq!(Vec::<String>::from_fix_value(b"A B  C"), Ok(vec!["A".to_string(), "B".to_string(), "C".to_string()]));
+  }
+
+  #[test]
+  fn it_should_format_typed_values() {
+    assert_eq!((-23i64).to_fix_value(), b"-23");
+    assert_eq!('A'.to_fix_value(), b"A");
+    assert_eq!(false.to_fix_value(), b"N");
+    assert_eq!("BRKR".to_fix_value(), b"BRKR");
+    assert_eq!(vec!["A".to_string(), "B".to_string()].to_fix_value(), b"A B");
+    assert_eq!(UtcDateOnly::new(2024, 2, 29).unwrap().to_fix_value(), b"20240229");
+  }
+}
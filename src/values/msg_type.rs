@@ -0,0 +1,239 @@
+use std::fmt;
+use std::str;
+
+use super::super::FIXValueError;
+use super::FromFixValue;
+
+macro_rules! msg_types {
+  ($($name:ident = $code:expr, $category:ident;)*) => {
+    /// `35 - MsgType` of FIX message
+    ///
+    /// Standard messages of FIX 4.0 - 5.0SP2 are variants of the enum. Values
+    /// starting with `U` are reserved for user defined messages and kept as
+    /// `Custom`, other values unknown to the crate are kept as `Other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fix_message::*;
+    ///
+    /// assert_eq!(MsgType::from("D"), MsgType::NewOrderSingle);
+    /// assert_eq!(MsgType::from("U1"), MsgType::Custom("U1".to_string()));
+    /// assert!(MsgType::Logon.is_admin());
+    /// assert!(MsgType::ExecutionReport.is_app());
+    /// assert_eq!(MsgType::MarketDataSnapshotFullRefresh.as_str(), "W");
+    /// ```
+    #[derive(PartialEq, Eq, Hash, Debug, Clone)]
+    pub enum MsgType {
+      $(
+        #[doc = concat!("`", $code, "`")]
+        $name,
+      )*
+      /// User defined message, value starts with `U`
+      Custom(String),
+      /// Message unknown to the crate
+      Other(String),
+    }
+
+    impl MsgType {
+      /// Returns `35 - MsgType` value
+      pub fn as_str(&self) -> &str {
+        match *self {
+          $(MsgType::$name => $code,)*
+          MsgType::Custom(ref value) | MsgType::Other(ref value) => value,
+        }
+      }
+
+      /// Returns `true` for session level messages
+      pub fn is_admin(&self) -> bool {
+        match *self {
+          $(MsgType::$name => msg_types!(@admin $category),)*
+          MsgType::Custom(..) | MsgType::Other(..) => false,
+        }
+      }
+    }
+
+    impl <'a> From<&'a str> for MsgType {
+      fn from(value: &'a str) -> MsgType {
+        match value {
+          $($code => MsgType::$name,)*
+          _ if value.starts_with('U') => MsgType::Custom(value.to_string()),
+          _ => MsgType::Other(value.to_string()),
+        }
+      }
+    }
+  };
+  (@admin admin) => { true };
+  (@admin app) => { false };
+}
+
+msg_types! {
+  Heartbeat = "0", admin;
+  TestRequest = "1", admin;
+  ResendRequest = "2", admin;
+  Reject = "3", admin;
+  SequenceReset = "4", admin;
+  Logout = "5", admin;
+  Logon = "A", admin;
+  XmlNonFix = "n", admin;
+  IndicationOfInterest = "6", app;
+  Advertisement = "7", app;
+  ExecutionReport = "8", app;
+  OrderCancelReject = "9", app;
+  News = "B", app;
+  Email = "C", app;
+  NewOrderSingle = "D", app;
+  NewOrderList = "E", app;
+  OrderCancelRequest = "F", app;
+  OrderCancelReplaceRequest = "G", app;
+  OrderStatusRequest = "H", app;
+  AllocationInstruction = "J", app;
+  ListCancelRequest = "K", app;
+  ListExecute = "L", app;
+  ListStatusRequest = "M", app;
+  ListStatus = "N", app;
+  AllocationInstructionAck = "P", app;
+  DontKnowTrade = "Q", app;
+  QuoteRequest = "R", app;
+  Quote = "S", app;
+  SettlementInstructions = "T", app;
+  MarketDataRequest = "V", app;
+  MarketDataSnapshotFullRefresh = "W", app;
+  MarketDataIncrementalRefresh = "X", app;
+  MarketDataRequestReject = "Y", app;
+  QuoteCancel = "Z", app;
+  QuoteStatusRequest = "a", app;
+  MassQuoteAcknowledgement = "b", app;
+  SecurityDefinitionRequest = "c", app;
+  SecurityDefinition = "d", app;
+  SecurityStatusRequest = "e", app;
+  SecurityStatus = "f", app;
+  TradingSessionStatusRequest = "g", app;
+  TradingSessionStatus = "h", app;
+  MassQuote = "i", app;
+  BusinessMessageReject = "j", app;
+  BidRequest = "k", app;
+  BidResponse = "l", app;
+  ListStrikePrice = "m", app;
+  RegistrationInstructions = "o", app;
+  RegistrationInstructionsResponse = "p", app;
+  OrderMassCancelRequest = "q", app;
+  OrderMassCancelReport = "r", app;
+  NewOrderCross = "s", app;
+  CrossOrderCancelReplaceRequest = "t", app;
+  CrossOrderCancelRequest = "u", app;
+  SecurityTypeRequest = "v", app;
+  SecurityTypes = "w", app;
+  SecurityListRequest = "x", app;
+  SecurityList = "y", app;
+  DerivativeSecurityListRequest = "z", app;
+  DerivativeSecurityList = "AA", app;
+  NewOrderMultileg = "AB", app;
+  MultilegOrderCancelReplace = "AC", app;
+  TradeCaptureReportRequest = "AD", app;
+  TradeCaptureReport = "AE", app;
+  OrderMassStatusRequest = "AF", app;
+  QuoteRequestReject = "AG", app;
+  RfqRequest = "AH", app;
+  QuoteStatusReport = "AI", app;
+  QuoteResponse = "AJ", app;
+  Confirmation = "AK", app;
+  PositionMaintenanceRequest = "AL", app;
+  PositionMaintenanceReport = "AM", app;
+  RequestForPositions = "AN", app;
+  RequestForPositionsAck = "AO", app;
+  PositionReport = "AP", app;
+  TradeCaptureReportRequestAck = "AQ", app;
+  TradeCaptureReportAck = "AR", app;
+  AllocationReport = "AS", app;
+  AllocationReportAck = "AT", app;
+  ConfirmationAck = "AU", app;
+  SettlementInstructionRequest = "AV", app;
+  AssignmentReport = "AW", app;
+  CollateralRequest = "AX", app;
+  CollateralAssignment = "AY", app;
+  CollateralResponse = "AZ", app;
+  CollateralReport = "BA", app;
+  CollateralInquiry = "BB", app;
+  NetworkCounterpartySystemStatusRequest = "BC", app;
+  NetworkCounterpartySystemStatusResponse = "BD", app;
+  UserRequest = "BE", app;
+  UserResponse = "BF", app;
+  CollateralInquiryAck = "BG", app;
+  ConfirmationRequest = "BH", app;
+  TradingSessionListRequest = "BI", app;
+  TradingSessionList = "BJ", app;
+  SecurityListUpdateReport = "BK", app;
+  AdjustedPositionReport = "BL", app;
+  AllocationInstructionAlert = "BM", app;
+  ExecutionAcknowledgement = "BN", app;
+  ContraryIntentionReport = "BO", app;
+  SecurityDefinitionUpdateReport = "BP", app;
+  SettlementObligationReport = "BQ", app;
+  DerivativeSecurityListUpdateReport = "BR", app;
+  TradingSessionListUpdateReport = "BS", app;
+  MarketDefinitionRequest = "BT", app;
+  MarketDefinition = "BU", app;
+  MarketDefinitionUpdateReport = "BV", app;
+  ApplicationMessageRequest = "BW", app;
+  ApplicationMessageRequestAck = "BX", app;
+  ApplicationMessageReport = "BY", app;
+  OrderMassActionReport = "BZ", app;
+  OrderMassActionRequest = "CA", app;
+  UserNotification = "CB", app;
+  StreamAssignmentRequest = "CC", app;
+  StreamAssignmentReport = "CD", app;
+  StreamAssignmentReportAck = "CE", app;
+}
+
+impl MsgType {
+  /// Returns `true` for application level messages, including user defined
+  /// and unknown ones
+  pub fn is_app(&self) -> bool {
+    !self.is_admin()
+  }
+}
+
+impl fmt::Display for MsgType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.as_str())
+  }
+}
+
+impl FromFixValue for MsgType {
+  fn from_fix_value(value: &[u8]) -> Result<MsgType, FIXValueError> {
+    match str::from_utf8(value) {
+      Ok("") => Err(FIXValueError::InvalidEnumValue),
+      Ok(value) => Ok(MsgType::from(value)),
+      Err(_) => Err(FIXValueError::InvalidEncoding),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn it_should_convert_msg_type_values() {
+    let cases = vec![("0", MsgType::Heartbeat), ("A", MsgType::Logon), ("n", MsgType::XmlNonFix),
+      ("AE", MsgType::TradeCaptureReport), ("CE", MsgType::StreamAssignmentReportAck),
+      ("UXY", MsgType::Custom("UXY".to_string())), ("ZZ", MsgType::Other("ZZ".to_string()))];
+
+    for (value, msg_type) in cases {
+      assert_eq!(MsgType::from_fix_value(value.as_bytes()), Ok(msg_type.clone()));
+      assert_eq!(msg_type.as_str(), value);
+    }
+    assert_eq!(MsgType::from_fix_value(b""), Err(FIXValueError::InvalidEnumValue));
+  }
+
+  #[test]
+  fn it_should_classify_messages() {
+    let admin = ["0", "1", "2", "3", "4", "5", "A", "n"];
+
+    for msg_type in ["0", "1", "2", "3", "4", "5", "A", "n", "8", "D", "W", "j", "BE", "U1", "ZZ"].iter().map(|value| MsgType::from(*value)) {
+      assert_eq!(msg_type.is_admin(), admin.contains(&msg_type.as_str()));
+      assert_eq!(msg_type.is_app(), !msg_type.is_admin());
+    }
+  }
+}
@@ -0,0 +1,60 @@
+use std::convert::TryFrom;
+
+use time::{Date, Month, OffsetDateTime, Time, UtcOffset};
+
+use super::super::FIXValueError;
+use super::{UtcDateOnly, UtcTimestamp};
+
+/// Fails with `FIXValueError::InvalidUtcTimestamp` for leap second `60`,
+/// which `time` does not support
+impl TryFrom<UtcTimestamp> for OffsetDateTime {
+  type Error = FIXValueError;
+
+  fn try_from(timestamp: UtcTimestamp) -> Result<OffsetDateTime, FIXValueError> {
+    let date = Month::try_from(timestamp.month()).ok()
+      .and_then(|month| Date::from_calendar_date(i32::from(timestamp.year()), month, timestamp.day()).ok());
+    let time = Time::from_hms_nano(timestamp.hour(), timestamp.minute(), timestamp.second(), timestamp.nanosecond()).ok();
+    match (date, time) {
+      (Some(date), Some(time)) => Ok(date.with_time(time).assume_utc()),
+      _ => Err(FIXValueError::InvalidUtcTimestamp),
+    }
+  }
+}
+
+/// Date and time are converted to UTC first, fails with
+/// `FIXValueError::InvalidUtcTimestamp` when year is out of `0..=9999` range
+impl TryFrom<OffsetDateTime> for UtcTimestamp {
+  type Error = FIXValueError;
+
+  fn try_from(date_time: OffsetDateTime) -> Result<UtcTimestamp, FIXValueError> {
+    let date_time = date_time.to_offset(UtcOffset::UTC);
+    u16::try_from(date_time.year()).ok()
+      .and_then(|year| UtcDateOnly::new(year, u8::from(date_time.month()), date_time.day()))
+      .and_then(|date| UtcTimestamp::new(date, date_time.hour(), date_time.minute(), date_time.second(),
+        date_time.nanosecond()))
+      .ok_or(FIXValueError::InvalidUtcTimestamp)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use super::super::FromFixValue;
+
+  #[test]
+  fn it_should_convert_timestamp_to_time_and_back() {
+    let timestamp = UtcTimestamp::from_fix_value(b"19980604-07:58:28.123456789").unwrap();
+    let date_time = OffsetDateTime::try_from(timestamp).unwrap();
+
+    assert_eq!(date_time.unix_timestamp_nanos(), timestamp.unix_timestamp_nanos());
+    assert_eq!(UtcTimestamp::try_from(date_time), Ok(timestamp));
+    assert_eq!(UtcTimestamp::try_from(date_time.to_offset(UtcOffset::from_hms(10, 0, 0).unwrap())), Ok(timestamp));
+  }
+
+  #[test]
+  fn it_should_complain_when_converting_leap_second_to_time() {
+    let timestamp = UtcTimestamp::from_fix_value(b"20161231-23:59:60").unwrap();
+
+    assert_eq!(OffsetDateTime::try_from(timestamp), Err(FIXValueError::InvalidUtcTimestamp));
+  }
+}
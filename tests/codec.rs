@@ -0,0 +1,68 @@
+#![cfg(feature = "tokio")]
+
+extern crate fix_message;
+extern crate futures;
+extern crate tokio;
+extern crate tokio_util;
+
+use fix_message::*;
+use futures::executor::block_on;
+use futures::{SinkExt, StreamExt};
+use tokio::io::AsyncWriteExt;
+use tokio_util::codec::{Framed, FramedRead};
+
+mod common;
+use common::*;
+
+fn brew_received_message(message: &FIXMessage) -> FIXMessage {
+  parse_bytes(&generate_bytes(message).unwrap()).unwrap()
+}
+
+#[test]
+fn codec_should_send_and_receive_fix_messages() {
+  let (client, server) = tokio::io::duplex(64);
+  let mut client = Framed::new(client, FIXCodec::new());
+  let mut server = Framed::new(server, FIXCodec::new());
+
+  let messages = vec![brew_heartbeat(vec![(112, "1")]), brew_heartbeat(vec![(112, "2")])];
+  let sent_messages = messages.clone();
+  let sender = std::thread::spawn(move || {
+    for message in sent_messages { block_on(client.send(message)).unwrap() }
+  });
+
+  assert_eq!(block_on(server.next()).unwrap().unwrap(), brew_received_message(&messages[0]));
+  assert_eq!(block_on(server.next()).unwrap().unwrap(), brew_received_message(&messages[1]));
+  sender.join().unwrap();
+}
+
+#[test]
+fn codec_should_keep_receiving_after_invalid_message() {
+  let (mut client, server) = tokio::io::duplex(1024);
+  let mut server = Framed::new(server, FixCodec::new());
+
+  let mut invalid_message = generate_bytes(&brew_heartbeat(vec![(112, "1")])).unwrap();
+  let checksum_start = invalid_message.len() - 4;
+  invalid_message[checksum_start] = b'9';
+  block_on(client.write_all(&invalid_message)).unwrap();
+  block_on(client.write_all(&generate_bytes(&brew_heartbeat(vec![(112, "2")])).unwrap())).unwrap();
+  drop(client);
+
+  assert_eq!(block_on(server.next()).unwrap().unwrap(), brew_received_message(&brew_heartbeat(vec![(112, "2")])));
+  assert!(block_on(server.next()).is_none());
+}
+
+#[test]
+fn codec_should_decode_fragmented_stream_with_garbage() {
+  let (mut writer, reader) = tokio::io::duplex(1024);
+  let mut reader = FramedRead::new(reader, FIXCodec::new());
+
+  let message = generate_bytes(&brew_heartbeat(vec![(112, "1")])).unwrap();
+  block_on(writer.write_all(b"garbage")).unwrap();
+  for chunk in message.chunks(7) { block_on(writer.write_all(chunk)).unwrap() }
+  block_on(writer.write_all(&message[..10])).unwrap();
+  drop(writer);
+
+  assert_eq!(block_on(reader.next()).unwrap().unwrap(), brew_received_message(&brew_heartbeat(vec![(112, "1")])));
+  assert_eq!(block_on(reader.next()).unwrap().unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+  assert!(block_on(reader.next()).is_none());
+}
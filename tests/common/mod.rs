@@ -0,0 +1,20 @@
+//! Message builders shared by integration tests
+
+#![allow(dead_code)]
+
+use fix_message::*;
+
+pub fn brew_message(version: &str, fields: Vec<(u32, &str)>) -> FIXMessage {
+  FIXMessage {
+    version: version.to_string(),
+    data: fields.into_iter()
+      .map(|(tag, value)| FIXMessageField { tag, value: value.as_bytes().to_vec() })
+      .collect()
+  }
+}
+
+/// Builds FIX.4.2 heartbeat with standard header followed by `fields`
+pub fn brew_heartbeat(fields: Vec<(u32, &str)>) -> FIXMessage {
+  let header = vec![(35, "0"), (49, "BRKR"), (56, "INVMGR"), (34, "235"), (52, "19980604-07:58:28")];
+  brew_message("FIX.4.2", header.into_iter().chain(fields).collect())
+}
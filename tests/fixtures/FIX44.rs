@@ -0,0 +1,1326 @@
+// Generated by fix_message `CodeGenerator` from FIX.4.4 data dictionary, do not edit
+
+use std::convert::TryFrom;
+
+use fix_message::*;
+
+/// Values of `22 - SecurityIDSource`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum SecurityIDSource {
+  /// `1`
+  Cusip,
+  /// `2`
+  Sedol,
+  /// `4`
+  IsinNumber,
+  /// `8`
+  ExchangeSymbol,
+}
+
+impl FromFixValue for SecurityIDSource {
+  fn from_fix_value(value: &[u8]) -> Result<SecurityIDSource, FIXValueError> {
+    match value {
+      b"1" => Ok(SecurityIDSource::Cusip),
+      b"2" => Ok(SecurityIDSource::Sedol),
+      b"4" => Ok(SecurityIDSource::IsinNumber),
+      b"8" => Ok(SecurityIDSource::ExchangeSymbol),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for SecurityIDSource {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      SecurityIDSource::Cusip => b"1".to_vec(),
+      SecurityIDSource::Sedol => b"2".to_vec(),
+      SecurityIDSource::IsinNumber => b"4".to_vec(),
+      SecurityIDSource::ExchangeSymbol => b"8".to_vec(),
+    }
+  }
+}
+
+/// Values of `39 - OrdStatus`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum OrdStatus {
+  /// `0`
+  New,
+  /// `1`
+  PartiallyFilled,
+  /// `2`
+  Filled,
+  /// `4`
+  Canceled,
+  /// `8`
+  Rejected,
+}
+
+impl FromFixValue for OrdStatus {
+  fn from_fix_value(value: &[u8]) -> Result<OrdStatus, FIXValueError> {
+    match value {
+      b"0" => Ok(OrdStatus::New),
+      b"1" => Ok(OrdStatus::PartiallyFilled),
+      b"2" => Ok(OrdStatus::Filled),
+      b"4" => Ok(OrdStatus::Canceled),
+      b"8" => Ok(OrdStatus::Rejected),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for OrdStatus {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      OrdStatus::New => b"0".to_vec(),
+      OrdStatus::PartiallyFilled => b"1".to_vec(),
+      OrdStatus::Filled => b"2".to_vec(),
+      OrdStatus::Canceled => b"4".to_vec(),
+      OrdStatus::Rejected => b"8".to_vec(),
+    }
+  }
+}
+
+/// Values of `40 - OrdType`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum OrdType {
+  /// `1`
+  Market,
+  /// `2`
+  Limit,
+}
+
+impl FromFixValue for OrdType {
+  fn from_fix_value(value: &[u8]) -> Result<OrdType, FIXValueError> {
+    match value {
+      b"1" => Ok(OrdType::Market),
+      b"2" => Ok(OrdType::Limit),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for OrdType {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      OrdType::Market => b"1".to_vec(),
+      OrdType::Limit => b"2".to_vec(),
+    }
+  }
+}
+
+/// Values of `54 - Side`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum Side {
+  /// `1`
+  Buy,
+  /// `2`
+  Sell,
+}
+
+impl FromFixValue for Side {
+  fn from_fix_value(value: &[u8]) -> Result<Side, FIXValueError> {
+    match value {
+      b"1" => Ok(Side::Buy),
+      b"2" => Ok(Side::Sell),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for Side {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      Side::Buy => b"1".to_vec(),
+      Side::Sell => b"2".to_vec(),
+    }
+  }
+}
+
+/// Values of `59 - TimeInForce`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum TimeInForce {
+  /// `0`
+  Day,
+  /// `1`
+  GoodTillCancel,
+  /// `3`
+  ImmediateOrCancel,
+}
+
+impl FromFixValue for TimeInForce {
+  fn from_fix_value(value: &[u8]) -> Result<TimeInForce, FIXValueError> {
+    match value {
+      b"0" => Ok(TimeInForce::Day),
+      b"1" => Ok(TimeInForce::GoodTillCancel),
+      b"3" => Ok(TimeInForce::ImmediateOrCancel),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for TimeInForce {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      TimeInForce::Day => b"0".to_vec(),
+      TimeInForce::GoodTillCancel => b"1".to_vec(),
+      TimeInForce::ImmediateOrCancel => b"3".to_vec(),
+    }
+  }
+}
+
+/// Values of `98 - EncryptMethod`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum EncryptMethod {
+  /// `0`
+  NoneOther,
+}
+
+impl FromFixValue for EncryptMethod {
+  fn from_fix_value(value: &[u8]) -> Result<EncryptMethod, FIXValueError> {
+    match value {
+      b"0" => Ok(EncryptMethod::NoneOther),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for EncryptMethod {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      EncryptMethod::NoneOther => b"0".to_vec(),
+    }
+  }
+}
+
+/// Values of `150 - ExecType`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ExecType {
+  /// `0`
+  New,
+  /// `4`
+  Canceled,
+  /// `8`
+  Rejected,
+  /// `F`
+  Trade,
+}
+
+impl FromFixValue for ExecType {
+  fn from_fix_value(value: &[u8]) -> Result<ExecType, FIXValueError> {
+    match value {
+      b"0" => Ok(ExecType::New),
+      b"4" => Ok(ExecType::Canceled),
+      b"8" => Ok(ExecType::Rejected),
+      b"F" => Ok(ExecType::Trade),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for ExecType {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      ExecType::New => b"0".to_vec(),
+      ExecType::Canceled => b"4".to_vec(),
+      ExecType::Rejected => b"8".to_vec(),
+      ExecType::Trade => b"F".to_vec(),
+    }
+  }
+}
+
+/// Values of `263 - SubscriptionRequestType`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum SubscriptionRequestType {
+  /// `0`
+  Snapshot,
+  /// `1`
+  SnapshotPlusUpdates,
+  /// `2`
+  DisablePreviousSnapshotPlusUpdateRequest,
+}
+
+impl FromFixValue for SubscriptionRequestType {
+  fn from_fix_value(value: &[u8]) -> Result<SubscriptionRequestType, FIXValueError> {
+    match value {
+      b"0" => Ok(SubscriptionRequestType::Snapshot),
+      b"1" => Ok(SubscriptionRequestType::SnapshotPlusUpdates),
+      b"2" => Ok(SubscriptionRequestType::DisablePreviousSnapshotPlusUpdateRequest),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for SubscriptionRequestType {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      SubscriptionRequestType::Snapshot => b"0".to_vec(),
+      SubscriptionRequestType::SnapshotPlusUpdates => b"1".to_vec(),
+      SubscriptionRequestType::DisablePreviousSnapshotPlusUpdateRequest => b"2".to_vec(),
+    }
+  }
+}
+
+/// Values of `269 - MDEntryType`
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum MDEntryType {
+  /// `0`
+  Bid,
+  /// `1`
+  Offer,
+  /// `2`
+  Trade,
+}
+
+impl FromFixValue for MDEntryType {
+  fn from_fix_value(value: &[u8]) -> Result<MDEntryType, FIXValueError> {
+    match value {
+      b"0" => Ok(MDEntryType::Bid),
+      b"1" => Ok(MDEntryType::Offer),
+      b"2" => Ok(MDEntryType::Trade),
+      _ => Err(FIXValueError::InvalidEnumValue),
+    }
+  }
+}
+
+impl ToFixValue for MDEntryType {
+  fn to_fix_value(&self) -> Vec<u8> {
+    match *self {
+      MDEntryType::Bid => b"0".to_vec(),
+      MDEntryType::Offer => b"1".to_vec(),
+      MDEntryType::Trade => b"2".to_vec(),
+    }
+  }
+}
+
+/// Standard header
+#[derive(PartialEq, Debug, Clone)]
+pub struct StandardHeader {
+  /// `49 - SenderCompID`
+  pub sender_comp_id: String,
+  /// `56 - TargetCompID`
+  pub target_comp_id: String,
+  /// `115 - OnBehalfOfCompID`
+  pub on_behalf_of_comp_id: Option<String>,
+  /// `34 - MsgSeqNum`
+  pub msg_seq_num: u64,
+  /// `43 - PossDupFlag`
+  pub poss_dup_flag: Option<bool>,
+  /// `52 - SendingTime`
+  pub sending_time: UtcTimestamp,
+  /// `122 - OrigSendingTime`
+  pub orig_sending_time: Option<UtcTimestamp>,
+  /// `627 - NoHops`
+  pub no_hops: Vec<StandardHeaderNoHops>,
+}
+
+impl StandardHeader {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<StandardHeader, FIXConversionError> {
+    Ok(StandardHeader {
+      sender_comp_id: entry.get_required(49)?,
+      target_comp_id: entry.get_required(56)?,
+      on_behalf_of_comp_id: entry.get_optional(115)?,
+      msg_seq_num: entry.get_required(34)?,
+      poss_dup_flag: entry.get_optional(43)?,
+      sending_time: entry.get_required(52)?,
+      orig_sending_time: entry.get_optional(122)?,
+      no_hops: match entry.group(627) {
+        Some(group) => group.iter().map(StandardHeaderNoHops::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    data.push(FIXMessageField::from_value(49, &self.sender_comp_id));
+    data.push(FIXMessageField::from_value(56, &self.target_comp_id));
+    if let Some(ref value) = self.on_behalf_of_comp_id {
+      data.push(FIXMessageField::from_value(115, value));
+    }
+    data.push(FIXMessageField::from_value(34, &self.msg_seq_num));
+    if let Some(ref value) = self.poss_dup_flag {
+      data.push(FIXMessageField::from_value(43, value));
+    }
+    data.push(FIXMessageField::from_value(52, &self.sending_time));
+    if let Some(ref value) = self.orig_sending_time {
+      data.push(FIXMessageField::from_value(122, value));
+    }
+    if !self.no_hops.is_empty() {
+      data.push(FIXMessageField::from_value(627, &self.no_hops.len()));
+      for entry in &self.no_hops {
+        entry.push_fields(data);
+      }
+    }
+  }
+}
+
+/// Entry of `627 - NoHops` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct StandardHeaderNoHops {
+  /// `628 - HopCompID`
+  pub hop_comp_id: Option<String>,
+  /// `629 - HopSendingTime`
+  pub hop_sending_time: Option<UtcTimestamp>,
+  /// `630 - HopRefID`
+  pub hop_ref_id: Option<u64>,
+}
+
+impl StandardHeaderNoHops {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<StandardHeaderNoHops, FIXConversionError> {
+    Ok(StandardHeaderNoHops {
+      hop_comp_id: entry.get_optional(628)?,
+      hop_sending_time: entry.get_optional(629)?,
+      hop_ref_id: entry.get_optional(630)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.hop_comp_id {
+      data.push(FIXMessageField::from_value(628, value));
+    }
+    if let Some(ref value) = self.hop_sending_time {
+      data.push(FIXMessageField::from_value(629, value));
+    }
+    if let Some(ref value) = self.hop_ref_id {
+      data.push(FIXMessageField::from_value(630, value));
+    }
+  }
+}
+
+/// `8 - ExecutionReport` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct ExecutionReport {
+  pub header: StandardHeader,
+  /// `37 - OrderID`
+  pub order_id: String,
+  /// `11 - ClOrdID`
+  pub cl_ord_id: Option<String>,
+  /// `17 - ExecID`
+  pub exec_id: String,
+  /// `150 - ExecType`
+  pub exec_type: ExecType,
+  /// `39 - OrdStatus`
+  pub ord_status: OrdStatus,
+  /// `1 - Account`
+  pub account: Option<String>,
+  /// `55 - Symbol`
+  pub symbol: Option<String>,
+  /// `48 - SecurityID`
+  pub security_id: Option<String>,
+  /// `22 - SecurityIDSource`
+  pub security_id_source: Option<SecurityIDSource>,
+  /// `454 - NoSecurityAltID`
+  pub no_security_alt_id: Vec<ExecutionReportNoSecurityAltID>,
+  /// `54 - Side`
+  pub side: Side,
+  /// `38 - OrderQty`
+  pub order_qty: Option<FixDecimal>,
+  /// `152 - CashOrderQty`
+  pub cash_order_qty: Option<FixDecimal>,
+  /// `44 - Price`
+  pub price: Option<FixDecimal>,
+  /// `32 - LastQty`
+  pub last_qty: Option<FixDecimal>,
+  /// `31 - LastPx`
+  pub last_px: Option<FixDecimal>,
+  /// `151 - LeavesQty`
+  pub leaves_qty: FixDecimal,
+  /// `14 - CumQty`
+  pub cum_qty: FixDecimal,
+  /// `6 - AvgPx`
+  pub avg_px: FixDecimal,
+  /// `60 - TransactTime`
+  pub transact_time: Option<UtcTimestamp>,
+  /// `58 - Text`
+  pub text: Option<String>,
+}
+
+impl ExecutionReport {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<ExecutionReport, FIXConversionError> {
+    Ok(ExecutionReport {
+      header: StandardHeader::from_entry(entry)?,
+      order_id: entry.get_required(37)?,
+      cl_ord_id: entry.get_optional(11)?,
+      exec_id: entry.get_required(17)?,
+      exec_type: entry.get_required(150)?,
+      ord_status: entry.get_required(39)?,
+      account: entry.get_optional(1)?,
+      symbol: entry.get_optional(55)?,
+      security_id: entry.get_optional(48)?,
+      security_id_source: entry.get_optional(22)?,
+      no_security_alt_id: match entry.group(454) {
+        Some(group) => group.iter().map(ExecutionReportNoSecurityAltID::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+      side: entry.get_required(54)?,
+      order_qty: entry.get_optional(38)?,
+      cash_order_qty: entry.get_optional(152)?,
+      price: entry.get_optional(44)?,
+      last_qty: entry.get_optional(32)?,
+      last_px: entry.get_optional(31)?,
+      leaves_qty: entry.get_required(151)?,
+      cum_qty: entry.get_required(14)?,
+      avg_px: entry.get_required(6)?,
+      transact_time: entry.get_optional(60)?,
+      text: entry.get_optional(58)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    data.push(FIXMessageField::from_value(37, &self.order_id));
+    if let Some(ref value) = self.cl_ord_id {
+      data.push(FIXMessageField::from_value(11, value));
+    }
+    data.push(FIXMessageField::from_value(17, &self.exec_id));
+    data.push(FIXMessageField::from_value(150, &self.exec_type));
+    data.push(FIXMessageField::from_value(39, &self.ord_status));
+    if let Some(ref value) = self.account {
+      data.push(FIXMessageField::from_value(1, value));
+    }
+    if let Some(ref value) = self.symbol {
+      data.push(FIXMessageField::from_value(55, value));
+    }
+    if let Some(ref value) = self.security_id {
+      data.push(FIXMessageField::from_value(48, value));
+    }
+    if let Some(ref value) = self.security_id_source {
+      data.push(FIXMessageField::from_value(22, value));
+    }
+    if !self.no_security_alt_id.is_empty() {
+      data.push(FIXMessageField::from_value(454, &self.no_security_alt_id.len()));
+      for entry in &self.no_security_alt_id {
+        entry.push_fields(data);
+      }
+    }
+    data.push(FIXMessageField::from_value(54, &self.side));
+    if let Some(ref value) = self.order_qty {
+      data.push(FIXMessageField::from_value(38, value));
+    }
+    if let Some(ref value) = self.cash_order_qty {
+      data.push(FIXMessageField::from_value(152, value));
+    }
+    if let Some(ref value) = self.price {
+      data.push(FIXMessageField::from_value(44, value));
+    }
+    if let Some(ref value) = self.last_qty {
+      data.push(FIXMessageField::from_value(32, value));
+    }
+    if let Some(ref value) = self.last_px {
+      data.push(FIXMessageField::from_value(31, value));
+    }
+    data.push(FIXMessageField::from_value(151, &self.leaves_qty));
+    data.push(FIXMessageField::from_value(14, &self.cum_qty));
+    data.push(FIXMessageField::from_value(6, &self.avg_px));
+    if let Some(ref value) = self.transact_time {
+      data.push(FIXMessageField::from_value(60, value));
+    }
+    if let Some(ref value) = self.text {
+      data.push(FIXMessageField::from_value(58, value));
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for ExecutionReport {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<ExecutionReport, FIXConversionError> {
+    if message.get_str(35) != Some("8") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+      GroupSpec::new(454, 455, vec![455, 456]),
+    ];
+    ExecutionReport::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<ExecutionReport> for FIXMessage {
+  fn from(message: ExecutionReport) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "8")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// Entry of `454 - NoSecurityAltID` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct ExecutionReportNoSecurityAltID {
+  /// `455 - SecurityAltID`
+  pub security_alt_id: Option<String>,
+  /// `456 - SecurityAltIDSource`
+  pub security_alt_id_source: Option<String>,
+}
+
+impl ExecutionReportNoSecurityAltID {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<ExecutionReportNoSecurityAltID, FIXConversionError> {
+    Ok(ExecutionReportNoSecurityAltID {
+      security_alt_id: entry.get_optional(455)?,
+      security_alt_id_source: entry.get_optional(456)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.security_alt_id {
+      data.push(FIXMessageField::from_value(455, value));
+    }
+    if let Some(ref value) = self.security_alt_id_source {
+      data.push(FIXMessageField::from_value(456, value));
+    }
+  }
+}
+
+/// `0 - Heartbeat` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct Heartbeat {
+  pub header: StandardHeader,
+  /// `112 - TestReqID`
+  pub test_req_id: Option<String>,
+}
+
+impl Heartbeat {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<Heartbeat, FIXConversionError> {
+    Ok(Heartbeat {
+      header: StandardHeader::from_entry(entry)?,
+      test_req_id: entry.get_optional(112)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    if let Some(ref value) = self.test_req_id {
+      data.push(FIXMessageField::from_value(112, value));
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for Heartbeat {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<Heartbeat, FIXConversionError> {
+    if message.get_str(35) != Some("0") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+    ];
+    Heartbeat::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<Heartbeat> for FIXMessage {
+  fn from(message: Heartbeat) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "0")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// `A - Logon` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct Logon {
+  pub header: StandardHeader,
+  /// `98 - EncryptMethod`
+  pub encrypt_method: EncryptMethod,
+  /// `108 - HeartBtInt`
+  pub heart_bt_int: i64,
+  /// `141 - ResetSeqNumFlag`
+  pub reset_seq_num_flag: Option<bool>,
+}
+
+impl Logon {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<Logon, FIXConversionError> {
+    Ok(Logon {
+      header: StandardHeader::from_entry(entry)?,
+      encrypt_method: entry.get_required(98)?,
+      heart_bt_int: entry.get_required(108)?,
+      reset_seq_num_flag: entry.get_optional(141)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    data.push(FIXMessageField::from_value(98, &self.encrypt_method));
+    data.push(FIXMessageField::from_value(108, &self.heart_bt_int));
+    if let Some(ref value) = self.reset_seq_num_flag {
+      data.push(FIXMessageField::from_value(141, value));
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for Logon {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<Logon, FIXConversionError> {
+    if message.get_str(35) != Some("A") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+    ];
+    Logon::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<Logon> for FIXMessage {
+  fn from(message: Logon) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "A")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// `V - MarketDataRequest` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataRequest {
+  pub header: StandardHeader,
+  /// `262 - MDReqID`
+  pub md_req_id: String,
+  /// `263 - SubscriptionRequestType`
+  pub subscription_request_type: SubscriptionRequestType,
+  /// `264 - MarketDepth`
+  pub market_depth: i64,
+  /// `267 - NoMDEntryTypes`
+  pub no_md_entry_types: Vec<MarketDataRequestNoMDEntryTypes>,
+  /// `146 - NoRelatedSym`
+  pub no_related_sym: Vec<MarketDataRequestNoRelatedSym>,
+}
+
+impl MarketDataRequest {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataRequest, FIXConversionError> {
+    Ok(MarketDataRequest {
+      header: StandardHeader::from_entry(entry)?,
+      md_req_id: entry.get_required(262)?,
+      subscription_request_type: entry.get_required(263)?,
+      market_depth: entry.get_required(264)?,
+      no_md_entry_types: match entry.group(267) {
+        Some(group) => group.iter().map(MarketDataRequestNoMDEntryTypes::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+      no_related_sym: match entry.group(146) {
+        Some(group) => group.iter().map(MarketDataRequestNoRelatedSym::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    data.push(FIXMessageField::from_value(262, &self.md_req_id));
+    data.push(FIXMessageField::from_value(263, &self.subscription_request_type));
+    data.push(FIXMessageField::from_value(264, &self.market_depth));
+    data.push(FIXMessageField::from_value(267, &self.no_md_entry_types.len()));
+    for entry in &self.no_md_entry_types {
+      entry.push_fields(data);
+    }
+    data.push(FIXMessageField::from_value(146, &self.no_related_sym.len()));
+    for entry in &self.no_related_sym {
+      entry.push_fields(data);
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for MarketDataRequest {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<MarketDataRequest, FIXConversionError> {
+    if message.get_str(35) != Some("V") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+      GroupSpec::new(267, 269, vec![269]),
+      GroupSpec::new(146, 55, vec![55, 48, 22]).with_group(GroupSpec::new(454, 455, vec![455, 456])),
+    ];
+    MarketDataRequest::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<MarketDataRequest> for FIXMessage {
+  fn from(message: MarketDataRequest) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "V")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// Entry of `267 - NoMDEntryTypes` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataRequestNoMDEntryTypes {
+  /// `269 - MDEntryType`
+  pub md_entry_type: MDEntryType,
+}
+
+impl MarketDataRequestNoMDEntryTypes {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataRequestNoMDEntryTypes, FIXConversionError> {
+    Ok(MarketDataRequestNoMDEntryTypes {
+      md_entry_type: entry.get_required(269)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    data.push(FIXMessageField::from_value(269, &self.md_entry_type));
+  }
+}
+
+/// Entry of `146 - NoRelatedSym` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataRequestNoRelatedSym {
+  /// `55 - Symbol`
+  pub symbol: Option<String>,
+  /// `48 - SecurityID`
+  pub security_id: Option<String>,
+  /// `22 - SecurityIDSource`
+  pub security_id_source: Option<SecurityIDSource>,
+  /// `454 - NoSecurityAltID`
+  pub no_security_alt_id: Vec<MarketDataRequestNoRelatedSymNoSecurityAltID>,
+}
+
+impl MarketDataRequestNoRelatedSym {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataRequestNoRelatedSym, FIXConversionError> {
+    Ok(MarketDataRequestNoRelatedSym {
+      symbol: entry.get_optional(55)?,
+      security_id: entry.get_optional(48)?,
+      security_id_source: entry.get_optional(22)?,
+      no_security_alt_id: match entry.group(454) {
+        Some(group) => group.iter().map(MarketDataRequestNoRelatedSymNoSecurityAltID::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.symbol {
+      data.push(FIXMessageField::from_value(55, value));
+    }
+    if let Some(ref value) = self.security_id {
+      data.push(FIXMessageField::from_value(48, value));
+    }
+    if let Some(ref value) = self.security_id_source {
+      data.push(FIXMessageField::from_value(22, value));
+    }
+    if !self.no_security_alt_id.is_empty() {
+      data.push(FIXMessageField::from_value(454, &self.no_security_alt_id.len()));
+      for entry in &self.no_security_alt_id {
+        entry.push_fields(data);
+      }
+    }
+  }
+}
+
+/// Entry of `454 - NoSecurityAltID` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataRequestNoRelatedSymNoSecurityAltID {
+  /// `455 - SecurityAltID`
+  pub security_alt_id: Option<String>,
+  /// `456 - SecurityAltIDSource`
+  pub security_alt_id_source: Option<String>,
+}
+
+impl MarketDataRequestNoRelatedSymNoSecurityAltID {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataRequestNoRelatedSymNoSecurityAltID, FIXConversionError> {
+    Ok(MarketDataRequestNoRelatedSymNoSecurityAltID {
+      security_alt_id: entry.get_optional(455)?,
+      security_alt_id_source: entry.get_optional(456)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.security_alt_id {
+      data.push(FIXMessageField::from_value(455, value));
+    }
+    if let Some(ref value) = self.security_alt_id_source {
+      data.push(FIXMessageField::from_value(456, value));
+    }
+  }
+}
+
+/// `W - MarketDataSnapshotFullRefresh` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataSnapshotFullRefresh {
+  pub header: StandardHeader,
+  /// `262 - MDReqID`
+  pub md_req_id: Option<String>,
+  /// `55 - Symbol`
+  pub symbol: Option<String>,
+  /// `48 - SecurityID`
+  pub security_id: Option<String>,
+  /// `22 - SecurityIDSource`
+  pub security_id_source: Option<SecurityIDSource>,
+  /// `454 - NoSecurityAltID`
+  pub no_security_alt_id: Vec<MarketDataSnapshotFullRefreshNoSecurityAltID>,
+  /// `268 - NoMDEntries`
+  pub no_md_entries: Vec<MarketDataSnapshotFullRefreshNoMDEntries>,
+}
+
+impl MarketDataSnapshotFullRefresh {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataSnapshotFullRefresh, FIXConversionError> {
+    Ok(MarketDataSnapshotFullRefresh {
+      header: StandardHeader::from_entry(entry)?,
+      md_req_id: entry.get_optional(262)?,
+      symbol: entry.get_optional(55)?,
+      security_id: entry.get_optional(48)?,
+      security_id_source: entry.get_optional(22)?,
+      no_security_alt_id: match entry.group(454) {
+        Some(group) => group.iter().map(MarketDataSnapshotFullRefreshNoSecurityAltID::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+      no_md_entries: match entry.group(268) {
+        Some(group) => group.iter().map(MarketDataSnapshotFullRefreshNoMDEntries::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    if let Some(ref value) = self.md_req_id {
+      data.push(FIXMessageField::from_value(262, value));
+    }
+    if let Some(ref value) = self.symbol {
+      data.push(FIXMessageField::from_value(55, value));
+    }
+    if let Some(ref value) = self.security_id {
+      data.push(FIXMessageField::from_value(48, value));
+    }
+    if let Some(ref value) = self.security_id_source {
+      data.push(FIXMessageField::from_value(22, value));
+    }
+    if !self.no_security_alt_id.is_empty() {
+      data.push(FIXMessageField::from_value(454, &self.no_security_alt_id.len()));
+      for entry in &self.no_security_alt_id {
+        entry.push_fields(data);
+      }
+    }
+    data.push(FIXMessageField::from_value(268, &self.no_md_entries.len()));
+    for entry in &self.no_md_entries {
+      entry.push_fields(data);
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for MarketDataSnapshotFullRefresh {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<MarketDataSnapshotFullRefresh, FIXConversionError> {
+    if message.get_str(35) != Some("W") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+      GroupSpec::new(454, 455, vec![455, 456]),
+      GroupSpec::new(268, 269, vec![269, 270, 271]),
+    ];
+    MarketDataSnapshotFullRefresh::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<MarketDataSnapshotFullRefresh> for FIXMessage {
+  fn from(message: MarketDataSnapshotFullRefresh) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "W")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// Entry of `454 - NoSecurityAltID` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataSnapshotFullRefreshNoSecurityAltID {
+  /// `455 - SecurityAltID`
+  pub security_alt_id: Option<String>,
+  /// `456 - SecurityAltIDSource`
+  pub security_alt_id_source: Option<String>,
+}
+
+impl MarketDataSnapshotFullRefreshNoSecurityAltID {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataSnapshotFullRefreshNoSecurityAltID, FIXConversionError> {
+    Ok(MarketDataSnapshotFullRefreshNoSecurityAltID {
+      security_alt_id: entry.get_optional(455)?,
+      security_alt_id_source: entry.get_optional(456)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.security_alt_id {
+      data.push(FIXMessageField::from_value(455, value));
+    }
+    if let Some(ref value) = self.security_alt_id_source {
+      data.push(FIXMessageField::from_value(456, value));
+    }
+  }
+}
+
+/// Entry of `268 - NoMDEntries` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct MarketDataSnapshotFullRefreshNoMDEntries {
+  /// `269 - MDEntryType`
+  pub md_entry_type: MDEntryType,
+  /// `270 - MDEntryPx`
+  pub md_entry_px: Option<FixDecimal>,
+  /// `271 - MDEntrySize`
+  pub md_entry_size: Option<FixDecimal>,
+}
+
+impl MarketDataSnapshotFullRefreshNoMDEntries {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<MarketDataSnapshotFullRefreshNoMDEntries, FIXConversionError> {
+    Ok(MarketDataSnapshotFullRefreshNoMDEntries {
+      md_entry_type: entry.get_required(269)?,
+      md_entry_px: entry.get_optional(270)?,
+      md_entry_size: entry.get_optional(271)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    data.push(FIXMessageField::from_value(269, &self.md_entry_type));
+    if let Some(ref value) = self.md_entry_px {
+      data.push(FIXMessageField::from_value(270, value));
+    }
+    if let Some(ref value) = self.md_entry_size {
+      data.push(FIXMessageField::from_value(271, value));
+    }
+  }
+}
+
+/// `D - NewOrderSingle` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct NewOrderSingle {
+  pub header: StandardHeader,
+  /// `11 - ClOrdID`
+  pub cl_ord_id: String,
+  /// `453 - NoPartyIDs`
+  pub no_party_ids: Vec<NewOrderSingleNoPartyIDs>,
+  /// `1 - Account`
+  pub account: Option<String>,
+  /// `55 - Symbol`
+  pub symbol: Option<String>,
+  /// `48 - SecurityID`
+  pub security_id: Option<String>,
+  /// `22 - SecurityIDSource`
+  pub security_id_source: Option<SecurityIDSource>,
+  /// `454 - NoSecurityAltID`
+  pub no_security_alt_id: Vec<NewOrderSingleNoSecurityAltID>,
+  /// `54 - Side`
+  pub side: Side,
+  /// `60 - TransactTime`
+  pub transact_time: UtcTimestamp,
+  /// `38 - OrderQty`
+  pub order_qty: Option<FixDecimal>,
+  /// `152 - CashOrderQty`
+  pub cash_order_qty: Option<FixDecimal>,
+  /// `40 - OrdType`
+  pub ord_type: OrdType,
+  /// `44 - Price`
+  pub price: Option<FixDecimal>,
+  /// `59 - TimeInForce`
+  pub time_in_force: Option<TimeInForce>,
+  /// `58 - Text`
+  pub text: Option<String>,
+}
+
+impl NewOrderSingle {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<NewOrderSingle, FIXConversionError> {
+    Ok(NewOrderSingle {
+      header: StandardHeader::from_entry(entry)?,
+      cl_ord_id: entry.get_required(11)?,
+      no_party_ids: match entry.group(453) {
+        Some(group) => group.iter().map(NewOrderSingleNoPartyIDs::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+      account: entry.get_optional(1)?,
+      symbol: entry.get_optional(55)?,
+      security_id: entry.get_optional(48)?,
+      security_id_source: entry.get_optional(22)?,
+      no_security_alt_id: match entry.group(454) {
+        Some(group) => group.iter().map(NewOrderSingleNoSecurityAltID::from_entry).collect::<Result<_, _>>()?,
+        None => vec![],
+      },
+      side: entry.get_required(54)?,
+      transact_time: entry.get_required(60)?,
+      order_qty: entry.get_optional(38)?,
+      cash_order_qty: entry.get_optional(152)?,
+      ord_type: entry.get_required(40)?,
+      price: entry.get_optional(44)?,
+      time_in_force: entry.get_optional(59)?,
+      text: entry.get_optional(58)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    data.push(FIXMessageField::from_value(11, &self.cl_ord_id));
+    if !self.no_party_ids.is_empty() {
+      data.push(FIXMessageField::from_value(453, &self.no_party_ids.len()));
+      for entry in &self.no_party_ids {
+        entry.push_fields(data);
+      }
+    }
+    if let Some(ref value) = self.account {
+      data.push(FIXMessageField::from_value(1, value));
+    }
+    if let Some(ref value) = self.symbol {
+      data.push(FIXMessageField::from_value(55, value));
+    }
+    if let Some(ref value) = self.security_id {
+      data.push(FIXMessageField::from_value(48, value));
+    }
+    if let Some(ref value) = self.security_id_source {
+      data.push(FIXMessageField::from_value(22, value));
+    }
+    if !self.no_security_alt_id.is_empty() {
+      data.push(FIXMessageField::from_value(454, &self.no_security_alt_id.len()));
+      for entry in &self.no_security_alt_id {
+        entry.push_fields(data);
+      }
+    }
+    data.push(FIXMessageField::from_value(54, &self.side));
+    data.push(FIXMessageField::from_value(60, &self.transact_time));
+    if let Some(ref value) = self.order_qty {
+      data.push(FIXMessageField::from_value(38, value));
+    }
+    if let Some(ref value) = self.cash_order_qty {
+      data.push(FIXMessageField::from_value(152, value));
+    }
+    data.push(FIXMessageField::from_value(40, &self.ord_type));
+    if let Some(ref value) = self.price {
+      data.push(FIXMessageField::from_value(44, value));
+    }
+    if let Some(ref value) = self.time_in_force {
+      data.push(FIXMessageField::from_value(59, value));
+    }
+    if let Some(ref value) = self.text {
+      data.push(FIXMessageField::from_value(58, value));
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for NewOrderSingle {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<NewOrderSingle, FIXConversionError> {
+    if message.get_str(35) != Some("D") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+      GroupSpec::new(453, 448, vec![448, 447, 452]),
+      GroupSpec::new(454, 455, vec![455, 456]),
+    ];
+    NewOrderSingle::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<NewOrderSingle> for FIXMessage {
+  fn from(message: NewOrderSingle) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "D")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// Entry of `453 - NoPartyIDs` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct NewOrderSingleNoPartyIDs {
+  /// `448 - PartyID`
+  pub party_id: Option<String>,
+  /// `447 - PartyIDSource`
+  pub party_id_source: Option<char>,
+  /// `452 - PartyRole`
+  pub party_role: Option<i64>,
+}
+
+impl NewOrderSingleNoPartyIDs {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<NewOrderSingleNoPartyIDs, FIXConversionError> {
+    Ok(NewOrderSingleNoPartyIDs {
+      party_id: entry.get_optional(448)?,
+      party_id_source: entry.get_optional(447)?,
+      party_role: entry.get_optional(452)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.party_id {
+      data.push(FIXMessageField::from_value(448, value));
+    }
+    if let Some(ref value) = self.party_id_source {
+      data.push(FIXMessageField::from_value(447, value));
+    }
+    if let Some(ref value) = self.party_role {
+      data.push(FIXMessageField::from_value(452, value));
+    }
+  }
+}
+
+/// Entry of `454 - NoSecurityAltID` repeating group
+#[derive(PartialEq, Debug, Clone)]
+pub struct NewOrderSingleNoSecurityAltID {
+  /// `455 - SecurityAltID`
+  pub security_alt_id: Option<String>,
+  /// `456 - SecurityAltIDSource`
+  pub security_alt_id_source: Option<String>,
+}
+
+impl NewOrderSingleNoSecurityAltID {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<NewOrderSingleNoSecurityAltID, FIXConversionError> {
+    Ok(NewOrderSingleNoSecurityAltID {
+      security_alt_id: entry.get_optional(455)?,
+      security_alt_id_source: entry.get_optional(456)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    if let Some(ref value) = self.security_alt_id {
+      data.push(FIXMessageField::from_value(455, value));
+    }
+    if let Some(ref value) = self.security_alt_id_source {
+      data.push(FIXMessageField::from_value(456, value));
+    }
+  }
+}
+
+/// `3 - Reject` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct Reject {
+  pub header: StandardHeader,
+  /// `45 - RefSeqNum`
+  pub ref_seq_num: u64,
+  /// `371 - RefTagID`
+  pub ref_tag_id: Option<i64>,
+  /// `372 - RefMsgType`
+  pub ref_msg_type: Option<String>,
+  /// `373 - SessionRejectReason`
+  pub session_reject_reason: Option<i64>,
+  /// `58 - Text`
+  pub text: Option<String>,
+}
+
+impl Reject {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<Reject, FIXConversionError> {
+    Ok(Reject {
+      header: StandardHeader::from_entry(entry)?,
+      ref_seq_num: entry.get_required(45)?,
+      ref_tag_id: entry.get_optional(371)?,
+      ref_msg_type: entry.get_optional(372)?,
+      session_reject_reason: entry.get_optional(373)?,
+      text: entry.get_optional(58)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    data.push(FIXMessageField::from_value(45, &self.ref_seq_num));
+    if let Some(ref value) = self.ref_tag_id {
+      data.push(FIXMessageField::from_value(371, value));
+    }
+    if let Some(ref value) = self.ref_msg_type {
+      data.push(FIXMessageField::from_value(372, value));
+    }
+    if let Some(ref value) = self.session_reject_reason {
+      data.push(FIXMessageField::from_value(373, value));
+    }
+    if let Some(ref value) = self.text {
+      data.push(FIXMessageField::from_value(58, value));
+    }
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for Reject {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<Reject, FIXConversionError> {
+    if message.get_str(35) != Some("3") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+    ];
+    Reject::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<Reject> for FIXMessage {
+  fn from(message: Reject) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "3")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
+
+/// `1 - TestRequest` message
+#[derive(PartialEq, Debug, Clone)]
+pub struct TestRequest {
+  pub header: StandardHeader,
+  /// `112 - TestReqID`
+  pub test_req_id: String,
+}
+
+impl TestRequest {
+  /// Reads the structure from fields of message tree or group entry
+  pub fn from_entry(entry: &FIXGroupEntry) -> Result<TestRequest, FIXConversionError> {
+    Ok(TestRequest {
+      header: StandardHeader::from_entry(entry)?,
+      test_req_id: entry.get_required(112)?,
+    })
+  }
+
+  /// Appends fields of the structure to `data`
+  pub fn push_fields(&self, data: &mut Vec<FIXMessageField>) {
+    self.header.push_fields(data);
+    data.push(FIXMessageField::from_value(112, &self.test_req_id));
+  }
+}
+
+impl <'a> TryFrom<&'a FIXMessage> for TestRequest {
+  type Error = FIXConversionError;
+
+  fn try_from(message: &'a FIXMessage) -> Result<TestRequest, FIXConversionError> {
+    if message.get_str(35) != Some("1") {
+      return Err(FIXConversionError::UnexpectedMsgType(message.get_str(35).unwrap_or("").to_string()))
+    }
+    let specs = [
+      GroupSpec::new(627, 628, vec![628, 629, 630]),
+    ];
+    TestRequest::from_entry(&message.group_tree(&specs)?)
+  }
+}
+
+impl From<TestRequest> for FIXMessage {
+  fn from(message: TestRequest) -> FIXMessage {
+    let mut data = vec![FIXMessageField::from_value(35, "1")];
+    message.push_fields(&mut data);
+    FIXMessage { version: "FIX.4.4".to_string(), data }
+  }
+}
@@ -1,9 +1,21 @@
 extern crate fix_message;
 extern crate fix_checksum;
 
+use std::convert::TryFrom;
+use std::error::Error;
+use std::time::Duration;
+
 use fix_message::*;
+use fix_checksum::FIXChecksumValidatorError;
 use fix_checksum::FIXChecksumValidatorError::*;
 
+mod common;
+use common::*;
+
+#[allow(dead_code)]
+#[path = "fixtures/FIX44.rs"]
+mod fix44;
+
 // Message parser ==============================================================
 
 #[test]
@@ -35,12 +47,31 @@ fn parser_should_complain_when_checksum_is_invalid() {
 }
 
 #[test]
-fn parser_should_complain_when_invalid_field_structure_found() {
+fn parser_should_complain_when_body_length_format_is_invalid() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=7A", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=250"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err(),
+    InvalidBodyLengthFormat("7A".parse::<usize>().unwrap_err()));
+}
+
+#[test]
+fn parser_should_complain_when_body_length_is_invalid() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=72", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=235"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err(), InvalidBodyLength(72, 73));
+}
+
+#[test]
+fn parser_should_complain_when_field_value_is_empty() {
   let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=", "49=BRKR", "56=INVMGR",
     "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=188"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidFieldStructure);
+  assert_eq!(parse(&message).unwrap_err(), EmptyFieldValue);
 }
 
 #[test]
@@ -49,7 +80,7 @@ fn parser_should_complain_when_the_first_field_is_incorrect() {
     "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidFirstField("9"));
+  assert_eq!(parse(&message).unwrap_err(), InvalidFirstField(9));
 }
 
 #[test]
@@ -58,7 +89,7 @@ fn parser_should_complain_when_the_second_field_is_incorrect() {
     "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidSecondField("35"));
+  assert_eq!(parse(&message).unwrap_err(), InvalidSecondField(35));
 }
 
 #[test]
@@ -67,7 +98,7 @@ fn parser_should_complain_when_the_third_field_is_incorrect() {
     "35=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
   let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
 
-  assert_eq!(parse(&message).unwrap_err(), InvalidThirdField("34"));
+  assert_eq!(parse(&message).unwrap_err(), InvalidThirdField(34));
 }
 
 #[test]
@@ -97,17 +128,542 @@ fn parser_should_parse_fix_message() {
   let expected_fix_message = FIXMessage {
     version: "FIX.4.2".to_string(),
     data: vec![
-      FIXMessageField { tag: "8".to_string(), value: "FIX.4.2".to_string() } ,
-      FIXMessageField { tag: "9".to_string(), value: "73".to_string() } ,
-      FIXMessageField { tag: "35".to_string(), value: "0".to_string() } ,
-      FIXMessageField { tag: "49".to_string(), value: "BRKR".to_string() } ,
-      FIXMessageField { tag: "56".to_string(), value: "INVMGR".to_string() } ,
-      FIXMessageField { tag: "34".to_string(), value: "235".to_string() } ,
-      FIXMessageField { tag: "52".to_string(), value: "19980604-07:58:28".to_string() } ,
-      FIXMessageField { tag: "112".to_string(), value: "19980604-07:58:28".to_string() } ,
-      FIXMessageField { tag: "10".to_string(), value: "236".to_string() }
+      FIXMessageField { tag: 8, value: b"FIX.4.2".to_vec() } ,
+      FIXMessageField { tag: 9, value: b"73".to_vec() } ,
+      FIXMessageField { tag: 35, value: b"0".to_vec() } ,
+      FIXMessageField { tag: 49, value: b"BRKR".to_vec() } ,
+      FIXMessageField { tag: 56, value: b"INVMGR".to_vec() } ,
+      FIXMessageField { tag: 34, value: b"235".to_vec() } ,
+      FIXMessageField { tag: 52, value: b"19980604-07:58:28".to_vec() } ,
+      FIXMessageField { tag: 112, value: b"19980604-07:58:28".to_vec() } ,
+      FIXMessageField { tag: 10, value: b"236".to_vec() }
     ]
   };
 
   assert_eq!(parse(&message).unwrap(), expected_fix_message);
 }
+
+#[test]
+fn parser_should_parse_borrowed_fix_message() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  let fix_message = parse_borrowed(&message).unwrap();
+
+  assert_eq!(fix_message.version, "FIX.4.2");
+  assert_eq!(fix_message.data[2], FIXMessageFieldRef { tag: 35, value: b"0" });
+  assert_eq!(fix_message.to_owned(), parse(&message).unwrap());
+}
+
+#[test]
+fn parser_should_validate_header_by_fix_version() {
+  let message_parts: Vec<&str> = vec!["8=FIXT.1.1", "9=68", "35=A", "49=BRKR", "56=INVMGR",
+    "34=1", "52=19980604-07:58:28", "98=0", "108=30", "1137=9", "10=238"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  let fix_message = parse(&message).unwrap();
+  assert_eq!(fix_message.fix_version(), Some(FixVersion::Fixt11));
+  assert_eq!(fix_message.get_as(1137), Ok(ApplVerId::Fix50Sp2));
+
+  let message_parts: Vec<&str> = vec!["8=FIX.4.5", "9=51", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "10=149"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(parse(&message).unwrap_err(), UnknownBeginString("FIX.4.5".to_string()));
+}
+
+#[test]
+fn parser_should_validate_sections_order_in_strict_mode() {
+  let options = ParseOptions::new().with_strict_field_order();
+  let message: &[u8] = b"8=FIX.4.4\x019=55\x0135=0\x0149=B\x0156=I\x0134=1\x0152=1\x01627=1\x01628=HOP\x01\
+    58=T\x0193=2\x0189=SG\x0110=090\x01";
+
+  let fix_message = parse_with_options(message, &options).unwrap();
+  assert_eq!(fix_message, parse_bytes(message).unwrap());
+
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=64", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "58=Text", "43=Y", "10=228"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  let err = parse_with_options(&message, &options).unwrap_err();
+  assert_eq!(err, FieldOutOfOrder(43));
+  assert_eq!(err.error.reject_reason().map(|reason| reason.code()), Some(14));
+}
+
+#[test]
+fn parser_should_parse_fix_message_bytes() {
+  let message: &[u8] = b"8=FIX.4.2\x019=42\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=1\x0158=\xc0\x00\xff\x0110=169\x01";
+
+  let fix_message = parse_bytes(message).unwrap();
+
+  assert_eq!(fix_message.data[7].value, b"\xc0\x00\xff".to_vec());
+  assert_eq!(fix_message.data[7].to_string(), "58=\u{fffd}\u{0}\u{fffd}");
+}
+
+#[test]
+fn parser_should_parse_data_field_containing_delimiter() {
+  let message: &[u8] = b"8=FIX.4.2\x019=48\x0135=0\x0149=BRKR\x0156=INVMGR\x0134=235\x0152=1\x0195=4\x0196=a\x01=b\x0110=211\x01";
+
+  let fix_message = parse_bytes(message).unwrap();
+
+  assert_eq!(fix_message.data[8].value, b"a\x01=b".to_vec());
+}
+
+#[test]
+fn parser_should_report_location_of_invalid_field() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=231"];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  let err = parse(&message).unwrap_err();
+
+  assert_eq!(err, InvalidChecksumValue);
+  assert_eq!(err.to_string(), "Invalid value of FIX message checksum (field 8, tag `10`, byte offset 88)");
+}
+
+#[test]
+fn parser_should_return_error_which_outlives_message() {
+  let err = {
+    let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+      "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=2ZZ"];
+    let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+    parse(&message).unwrap_err()
+  };
+  let err: Box<dyn Error + Send + Sync + 'static> = Box::new(err);
+
+  assert_eq!(err.downcast_ref::<FIXMessageLocatedError>().unwrap().error,
+    InvalidChecksum(ChecksumFieldInvalidFormat("2ZZ".parse::<u32>().unwrap_err())));
+  assert_eq!(err.source().unwrap().downcast_ref::<FIXChecksumValidatorError>(),
+    Some(&ChecksumFieldInvalidFormat("2ZZ".parse::<u32>().unwrap_err())));
+}
+
+// Message generator ===========================================================
+
+#[test]
+fn generator_should_complain_when_not_all_required_header_fields_presented() {
+  let mut message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  message.data.remove(1);
+
+  assert_eq!(generate(&message).unwrap_err(), NotAllRequiredFieldsFound);
+}
+
+#[test]
+fn generator_should_generate_fix_message() {
+  let message_parts: Vec<&str> = vec!["8=FIX.4.2", "9=73", "35=0", "49=BRKR", "56=INVMGR",
+    "34=235", "52=19980604-07:58:28", "112=19980604-07:58:28", "10=236", ""];
+  let message: String = message_parts.join(&(FIX_MESSAGE_DELIMITER.to_string()));
+
+  assert_eq!(generate(&brew_heartbeat(vec![(112, "19980604-07:58:28")])).unwrap(), message);
+}
+
+#[test]
+fn generator_should_generate_message_accepted_by_parser() {
+  let fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  let message = generate(&fix_message).unwrap();
+  let parsed_message = parse(&message).unwrap();
+
+  assert_eq!(parsed_message.version, fix_message.version);
+  assert_eq!(&parsed_message.data[2..8], &fix_message.data[..]);
+}
+
+#[test]
+fn generator_should_preserve_decimal_scale() {
+  let mut fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  fix_message.data.push(FIXMessageField::from_value(44, &FixDecimal::from_fix_value(b"100.2500").unwrap()));
+  fix_message.data.push(FIXMessageField::from_value(38, &(FixDecimal::new(15, 1) * FixDecimal::from(2))));
+
+  let message = generate(&fix_message).unwrap();
+  let parsed_message = parse(&message).unwrap();
+
+  assert!(message.contains("\u{1}44=100.2500\u{1}38=3.0\u{1}"));
+  assert_eq!(parsed_message.get_as::<FixDecimal>(44).unwrap().to_fix_value(), b"100.2500");
+}
+
+#[test]
+fn generator_should_generate_message_built_with_builder() {
+  let builder = FIXMessageBuilder::new(FixVersion::Fix44, MsgType::NewOrderSingle)
+    .field(11, "ORD1")
+    .group(453, vec![
+      FIXGroupEntryBuilder::new().field(448, "PARTY1").field(452, &1u32),
+      FIXGroupEntryBuilder::new().field(448, "PARTY2").field(452, &3u32),
+    ])
+    .field(44, &FixDecimal::new(1005, 1))
+    .header(34, &7u64)
+    .header(56, "INVMGR")
+    .header(49, "BRKR")
+    .with_sending_time();
+
+  let parsed_message = parse_bytes(&builder.generate_bytes().unwrap()).unwrap();
+  let tree = parsed_message.group_tree(&[GroupSpec::new(453, 448, vec![448, 452])]).unwrap();
+
+  assert_eq!(parsed_message.msg_type(), Some(MsgType::NewOrderSingle));
+  assert_eq!(parsed_message.seq_num(), Some(7));
+  assert!(parsed_message.get_as::<UtcTimestamp>(52).is_ok());
+  assert_eq!(tree.group(453).unwrap().len(), 2);
+  assert_eq!(parsed_message.get_as::<FixDecimal>(44).unwrap().to_string(), "100.5");
+}
+
+// Field lookup ================================================================
+
+#[test]
+fn message_should_look_up_standard_header_fields() {
+  let fix_message = parse(&generate(&brew_heartbeat(vec![(112, "19980604-07:58:28")])).unwrap()).unwrap();
+
+  assert_eq!(fix_message.msg_type(), Some(MsgType::Heartbeat));
+  assert_eq!(fix_message.sender_comp_id(), Some("BRKR"));
+  assert_eq!(fix_message.target_comp_id(), Some("INVMGR"));
+  assert_eq!(fix_message.seq_num(), Some(235));
+  assert!(fix_message.contains(112));
+  assert!(!fix_message.contains(58));
+}
+
+#[test]
+fn message_should_look_up_repeated_fields() {
+  let mut fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  fix_message.data.push(FIXMessageField { tag: 58, value: b"first".to_vec() });
+  fix_message.data.push(FIXMessageField { tag: 58, value: b"second".to_vec() });
+  let index = fix_message.index();
+
+  assert_eq!(fix_message.get_all(58).map(|field| field.value_str().unwrap()).collect::<Vec<&str>>(),
+    vec!["first", "second"]);
+  assert_eq!(index.get_all(58).count(), 2);
+  assert_eq!(index.first_after(58, 6).map(|(position, _)| position), Some(7));
+}
+
+#[test]
+fn message_should_convert_typed_values() {
+  let mut fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  fix_message.data.push(FIXMessageField { tag: 44, value: b"100.2500".to_vec() });
+  let fix_message = parse(&generate(&fix_message).unwrap()).unwrap();
+  let index = fix_message.index();
+
+  assert_eq!(fix_message.get_as::<u64>(34), Ok(235));
+  assert_eq!(fix_message.get_as::<FixDecimal>(44).unwrap().to_string(), "100.2500");
+  assert_eq!(index.get_as::<UtcTimestamp>(52), fix_message.get_as::<UtcTimestamp>(112));
+  assert_eq!(index.get_as::<bool>(35), Err(FIXValueError::InvalidBoolean));
+  assert_eq!(index.get_as::<String>(58), Err(FIXValueError::FieldNotFound(58)));
+}
+
+#[test]
+fn message_should_measure_latency_between_timestamps() {
+  let mut fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  fix_message.data[4].value = b"19980604-07:58:28.000250".to_vec();
+  fix_message.data.push(FIXMessageField { tag: 122, value: b"19980604-07:58:27.999".to_vec() });
+  let fix_message = parse(&generate(&fix_message).unwrap()).unwrap();
+
+  let sending_time = fix_message.get_as::<UtcTimestamp>(52).unwrap();
+  let orig_sending_time = fix_message.get_as::<UtcTimestamp>(122).unwrap();
+
+  assert_eq!(sending_time.duration_since(&orig_sending_time), Some(Duration::from_micros(1_250)));
+  assert_eq!(sending_time.to_string(), "19980604-07:58:28.000250");
+}
+
+// Frame decoder ===============================================================
+
+#[test]
+fn decoder_should_decode_fragmented_and_concatenated_messages() {
+  let fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  let message = generate_bytes(&fix_message).unwrap();
+  let mut stream = b"garbage".to_vec();
+  stream.extend_from_slice(&message);
+  stream.extend_from_slice(&message);
+
+  let mut decoder = FIXFrameDecoder::new();
+  let mut events = vec![];
+  for chunk in stream.chunks(10) {
+    decoder.feed(chunk);
+    while let Some(event) = decoder.decode() { events.push(event) }
+  }
+
+  assert_eq!(events.len(), 3);
+  assert_eq!(events[0], FIXFrameEvent::Skipped(b"garbage".to_vec()));
+  assert_eq!(events[1], FIXFrameEvent::Message(parse_bytes(&message).unwrap()));
+  assert_eq!(events[2], FIXFrameEvent::Message(parse_bytes(&message).unwrap()));
+  assert!(decoder.buffer().is_empty());
+}
+
+// Data dictionary =============================================================
+
+fn dictionary_path() -> String {
+  format!("{}/tests/fixtures/FIX44.xml", env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn dictionary_should_load_quickfix_specification() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+
+  assert_eq!(dictionary.version(), "FIX.4.4");
+  assert_eq!(dictionary.fields().count(), 63);
+  assert_eq!(dictionary.messages().count(), 8);
+  assert_eq!(dictionary.header()[0], MemberDefinition::Field { tag: 8, required: true });
+  assert_eq!(dictionary.trailer().last(), Some(&MemberDefinition::Field { tag: 10, required: true }));
+
+  let ord_type = dictionary.field_by_name("OrdType").unwrap();
+  assert_eq!((ord_type.tag, &ord_type.data_type), (40, &FIXDataType::Char));
+  assert_eq!(ord_type.values[1], FieldValueDefinition { value: "2".to_string(), description: "LIMIT".to_string() });
+  assert_eq!(dictionary.field(44).unwrap().data_type, FIXDataType::Price);
+}
+
+#[test]
+fn dictionary_should_load_messages_components_and_groups() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+
+  let logon = dictionary.message("A").unwrap();
+  assert_eq!((logon.name.as_str(), logon.category), ("Logon", MessageCategory::Admin));
+
+  let market_data_request = dictionary.message("V").unwrap();
+  assert_eq!(market_data_request.category, MessageCategory::App);
+  match market_data_request.members[4] {
+    MemberDefinition::Group(ref group) => {
+      assert_eq!((group.tag, group.required), (146, true));
+      assert_eq!(group.members, vec![MemberDefinition::Component { name: "Instrument".to_string(), required: true }]);
+    },
+    ref member => panic!("Unexpected member {:?}", member),
+  }
+
+  let instrument = dictionary.component("Instrument").unwrap();
+  assert_eq!(instrument.members[0], MemberDefinition::Field { tag: 55, required: false });
+  assert!(!instrument.members[3].is_required());
+}
+
+#[test]
+fn dictionary_should_complain_when_file_not_found() {
+  let err = DataDictionary::from_file("tests/fixtures/FIX99.xml").unwrap_err();
+
+  match err {
+    DictionaryError::Io(ref err) => assert_eq!(err.kind(), std::io::ErrorKind::NotFound),
+    ref err => panic!("Unexpected error {:?}", err),
+  }
+  assert!(err.source().is_some());
+}
+
+fn brew_new_order_single(fields: Vec<(u32, &str)>) -> FIXMessage {
+  let header = vec![(35, "D"), (49, "BRKR"), (56, "INVMGR"), (34, "236"), (52, "19980604-07:59:30")];
+  brew_message("FIX.4.4", header.into_iter().chain(fields).collect())
+}
+
+#[test]
+fn dictionary_should_accept_valid_message() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+  let fix_message = brew_new_order_single(vec![(11, "ORD-1"), (453, "2"), (448, "A"), (452, "1"), (448, "B"),
+    (452, "3"), (55, "IBM"), (54, "1"), (60, "19980604-07:59:30.125"), (38, "100"), (40, "2"), (44, "100.2500")]);
+
+  assert_eq!(dictionary.validate(&fix_message), Ok(()));
+  assert_eq!(dictionary.validate(&parse(&generate(&fix_message).unwrap()).unwrap()), Ok(()));
+}
+
+#[test]
+fn dictionary_should_report_all_violations() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+  let fix_message = brew_new_order_single(vec![(11, "ORD-1"), (55, "IBM"), (54, "7"), (108, "30"), (9999, "x"),
+    (38, "1e3"), (40, "2"), (40, "2"), (43, "N"), (10, "000"), (58, "late")]);
+
+  let violations = dictionary.validate(&fix_message).unwrap_err();
+
+  assert_eq!(violations.iter().map(|violation| (violation.reason.code(), violation.tag, violation.field_index))
+    .collect::<Vec<_>>(), vec![
+      (5, 54, Some(7)),
+      (2, 108, Some(8)),
+      (0, 9999, Some(9)),
+      (6, 38, Some(10)),
+      (13, 40, Some(12)),
+      (14, 43, Some(13)),
+      (14, 58, Some(15)),
+      (1, 60, None),
+    ]);
+  assert_eq!(violations[0].to_string(), "Value is incorrect (out of range) for this tag (5): tag `54`, field 7");
+}
+
+#[test]
+fn dictionary_should_report_invalid_msg_type() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+  let mut fix_message = brew_new_order_single(vec![]);
+  fix_message.data[0].value = b"ZZ".to_vec();
+
+  let violations = dictionary.validate(&fix_message).unwrap_err();
+
+  assert_eq!(violations, vec![DictionaryViolation {
+    reason: SessionRejectReason::InvalidMsgType,
+    tag: 35,
+    field_index: Some(0),
+  }]);
+}
+
+#[test]
+fn dictionary_should_assemble_nested_repeating_groups() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+  let mut fix_message = brew_new_order_single(vec![(262, "MD-1"), (263, "1"), (264, "0"), (267, "2"), (269, "0"),
+    (269, "1"), (146, "2"), (55, "IBM"), (454, "2"), (455, "US4592001014"), (456, "4"), (455, "IBM.N"),
+    (456, "8"), (55, "MSFT")]);
+  fix_message.data[0].value = b"V".to_vec();
+
+  let tree = dictionary.group_tree(&fix_message).unwrap();
+  let symbols = tree.group(146).unwrap();
+  let alt_ids = symbols.entries[0].group(454).unwrap();
+
+  assert_eq!(dictionary.validate(&fix_message), Ok(()));
+  assert_eq!(tree.group(267).unwrap().len(), 2);
+  assert_eq!(symbols.iter().map(|entry| entry.get(55).unwrap().value_str().unwrap()).collect::<Vec<_>>(),
+    vec!["IBM", "MSFT"]);
+  assert_eq!(alt_ids.iter().map(|entry| entry.get(456).unwrap().value_str().unwrap()).collect::<Vec<_>>(),
+    vec!["4", "8"]);
+  assert!(symbols.entries[1].group(454).is_none());
+}
+
+#[test]
+fn dictionary_should_report_invalid_repeating_group() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+  let mut fix_message = brew_new_order_single(vec![(262, "MD-1"), (263, "1"), (264, "0"), (267, "3"), (269, "0"),
+    (269, "1"), (146, "1"), (55, "IBM")]);
+  fix_message.data[0].value = b"V".to_vec();
+
+  assert_eq!(dictionary.group_tree(&fix_message), Err(FIXGroupError::GroupCountMismatch(267, 3, 2)));
+  assert_eq!(dictionary.validate(&fix_message), Err(vec![DictionaryViolation {
+    reason: SessionRejectReason::IncorrectNumInGroupCount,
+    tag: 267,
+    field_index: Some(8),
+  }]));
+}
+
+// Code generator ==============================================================
+
+#[test]
+fn codegen_should_generate_golden_file() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+
+  assert_eq!(CodeGenerator::new(&dictionary).generate(), include_str!("fixtures/FIX44.rs"));
+}
+
+#[test]
+fn codegen_should_limit_generated_messages() {
+  let dictionary = DataDictionary::from_file(dictionary_path()).unwrap();
+  let code = CodeGenerator::new(&dictionary).with_messages(&["8", "W"]).generate();
+
+  assert!(code.contains("pub struct StandardHeader {"));
+  assert!(code.contains("pub struct ExecutionReport {"));
+  assert!(code.contains("pub struct MarketDataSnapshotFullRefreshNoMDEntries {"));
+  assert!(!code.contains("pub struct NewOrderSingle {"));
+  assert!(!code.contains("pub enum TimeInForce {"));
+}
+
+#[test]
+fn codegen_should_convert_message_into_typed_structure_and_back() {
+  let fix_message = brew_new_order_single(vec![(11, "ORD-1"), (453, "2"), (448, "A"), (452, "1"), (448, "B"),
+    (452, "3"), (55, "IBM"), (54, "1"), (60, "19980604-07:59:30.125"), (38, "100"), (40, "2"), (44, "100.2500")]);
+
+  let order = fix44::NewOrderSingle::try_from(&fix_message).unwrap();
+
+  assert_eq!(order.header.sender_comp_id, "BRKR");
+  assert_eq!(order.header.msg_seq_num, 236);
+  assert_eq!(order.cl_ord_id, "ORD-1");
+  assert_eq!(order.no_party_ids.iter().map(|party| party.party_role).collect::<Vec<_>>(), vec![Some(1), Some(3)]);
+  assert_eq!(order.side, fix44::Side::Buy);
+  assert_eq!(order.ord_type, fix44::OrdType::Limit);
+  assert_eq!(order.price, Some("100.2500".parse().unwrap()));
+  assert_eq!(order.time_in_force, None);
+  assert_eq!(FIXMessage::from(order), fix_message);
+}
+
+#[test]
+fn codegen_should_generate_typed_structure() {
+  let header = fix44::StandardHeader {
+    sender_comp_id: "BRKR".to_string(),
+    target_comp_id: "INVMGR".to_string(),
+    on_behalf_of_comp_id: None,
+    msg_seq_num: 237,
+    poss_dup_flag: None,
+    sending_time: UtcTimestamp::new(UtcDateOnly::new(1998, 6, 4).unwrap(), 8, 3, 31, 0).unwrap(),
+    orig_sending_time: None,
+    no_hops: vec![],
+  };
+  let snapshot = fix44::MarketDataSnapshotFullRefresh {
+    header,
+    md_req_id: Some("MD-1".to_string()),
+    symbol: Some("IBM".to_string()),
+    security_id: None,
+    security_id_source: None,
+    no_security_alt_id: vec![],
+    no_md_entries: vec![
+      fix44::MarketDataSnapshotFullRefreshNoMDEntries {
+        md_entry_type: fix44::MDEntryType::Bid,
+        md_entry_px: Some("101.25".parse().unwrap()),
+        md_entry_size: Some(FixDecimal::from(500)),
+      },
+      fix44::MarketDataSnapshotFullRefreshNoMDEntries {
+        md_entry_type: fix44::MDEntryType::Offer,
+        md_entry_px: Some("101.50".parse().unwrap()),
+        md_entry_size: None,
+      },
+    ],
+  };
+
+  let message = generate(&FIXMessage::from(snapshot.clone())).unwrap();
+
+  assert!(message.contains(&["35=W", "49=BRKR", "56=INVMGR", "34=237", "52=19980604-08:03:31", "262=MD-1", "55=IBM",
+    "268=2", "269=0", "270=101.25", "271=500", "269=1", "270=101.50", "10="].join(&FIX_MESSAGE_DELIMITER.to_string())));
+  assert_eq!(fix44::MarketDataSnapshotFullRefresh::try_from(&parse(&message).unwrap()), Ok(snapshot));
+}
+
+#[test]
+fn codegen_should_complain_about_invalid_message() {
+  let fix_message = brew_new_order_single(vec![(11, "ORD-1"), (55, "IBM"), (54, "7"), (40, "2")]);
+
+  assert_eq!(fix44::ExecutionReport::try_from(&fix_message), Err(FIXConversionError::UnexpectedMsgType("D".to_string())));
+  assert_eq!(fix44::NewOrderSingle::try_from(&fix_message),
+    Err(FIXConversionError::InvalidValue(54, FIXValueError::InvalidEnumValue)));
+
+  let fix_message = brew_new_order_single(vec![(11, "ORD-1"), (55, "IBM"), (54, "1"), (40, "2")]);
+  assert_eq!(fix44::NewOrderSingle::try_from(&fix_message), Err(FIXConversionError::MissingField(60)));
+}
+
+// Malformed input =============================================================
+
+fn brew_malformed_messages() -> Vec<Vec<u8>> {
+  let mut fix_message = brew_heartbeat(vec![(112, "19980604-07:58:28")]);
+  fix_message.data.push(FIXMessageField { tag: 95, value: b"3".to_vec() });
+  fix_message.data.push(FIXMessageField { tag: 96, value: b"a\x01b".to_vec() });
+  let message = generate_bytes(&fix_message).unwrap();
+  let trailer_start = message.len() - 7;
+
+  let mut messages: Vec<Vec<u8>> = (0..message.len()).map(|length| message[..length].to_vec()).collect();
+  for position in 0..trailer_start {
+    for &byte in b"\x00\x01=09\xff" {
+      // Checksum is recalculated, so that mutated message passes checksum validation
+      let mut mutated = message[..trailer_start].to_vec();
+      mutated[position] = byte;
+      let checksum = mutated.iter().fold(0, |cs: u32, &byte| (cs + u32::from(byte)) % 256);
+      mutated.extend_from_slice(format!("10={:03}\x01", checksum).as_bytes());
+      messages.push(mutated);
+    }
+  }
+  messages
+}
+
+#[test]
+fn parser_should_not_panic_on_malformed_input() {
+  for message in brew_malformed_messages() {
+    let _ = parse_bytes(&message);
+    let _ = parse_borrowed(&message).map_err(|err| err.to_string());
+  }
+}
+
+#[test]
+fn parser_should_parse_malformed_input_leniently() {
+  let options = ParseOptions::new().with_strict_field_order();
+  for message in brew_malformed_messages() {
+    let report = parse_lenient(&message, &options);
+
+    assert_eq!(report.is_valid(), parse_with_options(&message, &options).is_ok());
+    if report.is_valid() { assert_eq!(report.message, parse_bytes(&message).unwrap()) }
+  }
+}
+
+#[test]
+fn decoder_should_not_panic_on_malformed_input() {
+  let mut decoder = FIXFrameDecoder::new();
+  for message in brew_malformed_messages() {
+    decoder.feed(&message);
+    while decoder.decode().is_some() {}
+  }
+}